@@ -0,0 +1,52 @@
+use klaptik_codegen::*;
+use std::process::exit;
+
+const USAGE: &str =
+    "Usage: klaptik-pack <output> <id>:<image>[:<glyphs>]... <id>:<font>:<charset>...";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        exit(1);
+    }
+    if let Err(err) = pack(&args[0], &args[1..]) {
+        eprintln!("klaptik-pack: {}", err);
+        exit(1);
+    }
+}
+
+fn pack(output: &str, inputs: &[String]) -> Result<(), Error> {
+    let mut pack = SpritePack::new();
+    for input in inputs {
+        let mut parts = input.splitn(3, ':');
+        let id = parts
+            .next()
+            .and_then(|id| id.parse().ok())
+            .ok_or(Error::Format("invalid sprite id"))?;
+        let path = parts.next().ok_or(Error::Format("missing input path"))?;
+        let arg = parts.next();
+
+        if path.ends_with(".bdf") || path.ends_with(".psf") {
+            let charset = arg.ok_or(Error::Format("missing font charset"))?;
+            let (sprite, alphabet) = Font::load(path)?.sprite(charset)?;
+            pack.add(id, sprite, PackGlyphs::Alphabet(alphabet))?;
+        } else {
+            let glyphs = match arg {
+                Some(glyphs) => glyphs
+                    .parse()
+                    .map_err(|_| Error::Format("invalid glyph count"))?,
+                None => 1,
+            };
+            let sprite = Sprite::from_strip(&Bitmap::load(path)?, glyphs)?;
+            let glyphs = if glyphs == 1 {
+                PackGlyphs::Single
+            } else {
+                PackGlyphs::Sequential
+            };
+            pack.add(id, sprite, glyphs)?;
+        }
+    }
+    std::fs::write(output, pack.to_bytes())?;
+    Ok(())
+}
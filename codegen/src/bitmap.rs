@@ -0,0 +1,198 @@
+use crate::Error;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let data = std::fs::read(path)?;
+        match data.get(..2) {
+            Some(b"P1") | Some(b"P4") => Self::from_pbm(&data),
+            Some(b"BM") => Self::from_bmp(&data),
+            _ => Err(Error::Format("unsupported image format")),
+        }
+    }
+
+    pub fn from_pbm(data: &[u8]) -> Result<Self, Error> {
+        let mut header = PbmHeader { data, pos: 2 };
+        let width = header.number()?;
+        let height = header.number()?;
+        let mut bitmap = Self::new(width, height);
+
+        match &data[..2] {
+            b"P1" => {
+                let mut pixels = header.data[header.pos..]
+                    .split(|b| *b == b'\n')
+                    .flat_map(|line| line.split(|b| *b == b'#').next().unwrap_or_default())
+                    .filter(|b| !b.is_ascii_whitespace());
+                for pixel in bitmap.pixels.iter_mut() {
+                    *pixel = match pixels.next() {
+                        Some(b'1') => true,
+                        Some(b'0') => false,
+                        _ => return Err(Error::Format("malformed PBM raster")),
+                    };
+                }
+            }
+            b"P4" => {
+                let stride = width.div_ceil(8);
+                let raster = data
+                    .get(header.pos + 1..)
+                    .filter(|raster| raster.len() >= stride * height)
+                    .ok_or(Error::Format("truncated PBM raster"))?;
+                for y in 0..height {
+                    for x in 0..width {
+                        let byte = raster[y * stride + x / 8];
+                        bitmap.set_pixel(x, y, byte & (0x80 >> (x % 8)) != 0);
+                    }
+                }
+            }
+            _ => return Err(Error::Format("unsupported PBM variant")),
+        }
+
+        Ok(bitmap)
+    }
+
+    pub fn from_bmp(data: &[u8]) -> Result<Self, Error> {
+        let u16_at = |offset: usize| -> Result<u16, Error> {
+            data.get(offset..offset + 2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+                .ok_or(Error::Format("truncated BMP header"))
+        };
+        let u32_at = |offset: usize| -> Result<u32, Error> {
+            data.get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .ok_or(Error::Format("truncated BMP header"))
+        };
+
+        if data.get(..2) != Some(b"BM") {
+            return Err(Error::Format("missing BMP signature"));
+        }
+        let raster_offset = u32_at(10)? as usize;
+        let header_len = u32_at(14)? as usize;
+        if header_len < 40 {
+            return Err(Error::Format("unsupported BMP header"));
+        }
+        let width = u32_at(18)? as i32;
+        let height = u32_at(22)? as i32;
+        let bpp = u16_at(28)? as usize;
+        let compression = u32_at(30)?;
+        let colors = u32_at(46)? as usize;
+
+        if width <= 0 || height == 0 {
+            return Err(Error::Geometry("invalid BMP dimensions"));
+        }
+        if compression != 0 && !(compression == 3 && bpp == 32) {
+            return Err(Error::Format("compressed BMP is not supported"));
+        }
+        if !matches!(bpp, 1 | 4 | 8 | 24 | 32) {
+            return Err(Error::Format("unsupported BMP bit depth"));
+        }
+
+        let palette_offset = 14 + header_len;
+        let palette_len = if bpp <= 8 && colors == 0 {
+            1 << bpp
+        } else {
+            colors
+        };
+        let luma = |b: u8, g: u8, r: u8| (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000;
+        let palette = |idx: usize| -> Result<bool, Error> {
+            if idx >= palette_len {
+                return Err(Error::Format("BMP palette index out of range"));
+            }
+            let offset = palette_offset + idx * 4;
+            let bgr = data
+                .get(offset..offset + 3)
+                .ok_or(Error::Format("truncated BMP palette"))?;
+            Ok(luma(bgr[0], bgr[1], bgr[2]) < 128)
+        };
+
+        let top_down = height < 0;
+        let width = width as usize;
+        let height = height.unsigned_abs() as usize;
+        let stride = (bpp * width).div_ceil(32) * 4;
+        let raster = data
+            .get(raster_offset..raster_offset + stride * height)
+            .ok_or(Error::Format("truncated BMP raster"))?;
+
+        let mut bitmap = Self::new(width, height);
+        for row in 0..height {
+            let y = if top_down { row } else { height - row - 1 };
+            let line = &raster[row * stride..][..stride];
+            for x in 0..width {
+                let on = match bpp {
+                    1 => palette(((line[x / 8] >> (7 - x % 8)) & 1) as usize)?,
+                    4 => palette(((line[x / 2] >> (4 - (x % 2) * 4)) & 0x0f) as usize)?,
+                    8 => palette(line[x] as usize)?,
+                    _ => {
+                        let px = &line[x * bpp / 8..];
+                        luma(px[0], px[1], px[2]) < 128
+                    }
+                };
+                bitmap.set_pixel(x, y, on);
+            }
+        }
+
+        Ok(bitmap)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = on;
+        }
+    }
+}
+
+struct PbmHeader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl PbmHeader<'_> {
+    fn number(&mut self) -> Result<usize, Error> {
+        loop {
+            match self.data.get(self.pos) {
+                Some(b'#') => {
+                    while !matches!(self.data.get(self.pos), Some(b'\n') | None) {
+                        self.pos += 1;
+                    }
+                }
+                Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+                Some(_) => break,
+                None => return Err(Error::Format("truncated PBM header")),
+            }
+        }
+        let start = self.pos;
+        while self.data.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.data[start..self.pos])
+            .ok()
+            .and_then(|num| num.parse().ok())
+            .ok_or(Error::Format("malformed PBM header"))
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{Bitmap, Error, Sprite};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct Font {
+    width: usize,
+    height: usize,
+    glyphs: BTreeMap<char, Bitmap>,
+}
+
+impl Font {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let data = std::fs::read(path)?;
+        if data.starts_with(b"STARTFONT") {
+            let text = std::str::from_utf8(&data).map_err(|_| Error::Format("BDF is not UTF-8"))?;
+            Self::from_bdf(text)
+        } else {
+            Self::from_psf(&data)
+        }
+    }
+
+    pub fn from_bdf(text: &str) -> Result<Self, Error> {
+        let numbers = |args: &str| -> Result<Vec<i32>, Error> {
+            args.split_whitespace()
+                .map(|num| {
+                    num.parse()
+                        .map_err(|_| Error::Format("malformed BDF number"))
+                })
+                .collect()
+        };
+
+        let mut bounds = None;
+        let mut glyphs = BTreeMap::new();
+        let mut lines = text.lines().map(str::trim);
+
+        while let Some(line) = lines.next() {
+            let (key, args) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "FONTBOUNDINGBOX" => match numbers(args)?[..] {
+                    [w, h, x, y] if w > 0 && h > 0 => bounds = Some((w, h, x, y)),
+                    _ => return Err(Error::Format("malformed FONTBOUNDINGBOX")),
+                },
+                "STARTCHAR" => {
+                    let (font_w, font_h, font_x, font_y) =
+                        bounds.ok_or(Error::Format("missing FONTBOUNDINGBOX"))?;
+                    let mut encoding = None;
+                    let mut bbx = (font_w, font_h, font_x, font_y);
+                    for line in lines.by_ref() {
+                        let (key, args) = line.split_once(' ').unwrap_or((line, ""));
+                        match key {
+                            "ENCODING" => encoding = numbers(args)?.first().copied(),
+                            "BBX" => match numbers(args)?[..] {
+                                [w, h, x, y] => bbx = (w, h, x, y),
+                                _ => return Err(Error::Format("malformed BBX")),
+                            },
+                            "BITMAP" => break,
+                            _ => {}
+                        }
+                    }
+
+                    let (w, h, x, y) = bbx;
+                    let left = x - font_x;
+                    let top = (font_h + font_y) - (h + y);
+                    let mut glyph = Bitmap::new(font_w as usize, font_h as usize);
+                    for row in 0..h {
+                        let hex = lines.next().ok_or(Error::Format("truncated BDF bitmap"))?;
+                        for col in 0..w {
+                            let digit = hex
+                                .as_bytes()
+                                .get(col as usize / 4)
+                                .and_then(|d| (*d as char).to_digit(16))
+                                .unwrap_or(0);
+                            if digit & (8 >> (col % 4)) != 0 {
+                                let (px, py) = (left + col, top + row);
+                                if px >= 0 && py >= 0 {
+                                    glyph.set_pixel(px as usize, py as usize, true);
+                                }
+                            }
+                        }
+                    }
+                    if lines.next() != Some("ENDCHAR") {
+                        return Err(Error::Format("missing ENDCHAR"));
+                    }
+
+                    if let Some(ch) = encoding
+                        .filter(|code| *code >= 0)
+                        .and_then(|code| char::from_u32(code as u32))
+                    {
+                        glyphs.insert(ch, glyph);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (width, height, _, _) = bounds.ok_or(Error::Format("missing FONTBOUNDINGBOX"))?;
+        Ok(Self {
+            width: width as usize,
+            height: height as usize,
+            glyphs,
+        })
+    }
+
+    pub fn from_psf(data: &[u8]) -> Result<Self, Error> {
+        let u32_at = |offset: usize| -> Result<usize, Error> {
+            data.get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+                .ok_or(Error::Format("truncated PSF header"))
+        };
+
+        let (width, height, count, glyph_len, offset, has_table) = match data {
+            [0x36, 0x04, mode, charsize, ..] => {
+                let count = if mode & 0x01 != 0 { 512 } else { 256 };
+                let charsize = *charsize as usize;
+                (8, charsize, count, charsize, 4, mode & 0x06 != 0)
+            }
+            [0x72, 0xb5, 0x4a, 0x86, ..] => {
+                let header_len = u32_at(8)?;
+                let flags = u32_at(12)?;
+                let count = u32_at(16)?;
+                let glyph_len = u32_at(20)?;
+                let height = u32_at(24)?;
+                let width = u32_at(28)?;
+                (
+                    width,
+                    height,
+                    count,
+                    glyph_len,
+                    header_len,
+                    flags & 0x01 != 0,
+                )
+            }
+            _ => return Err(Error::Format("unsupported font format")),
+        };
+
+        let stride = width.div_ceil(8);
+        if width == 0 || height == 0 || stride * height > glyph_len {
+            return Err(Error::Format("invalid PSF glyph geometry"));
+        }
+        let raster = data
+            .get(offset..offset + count * glyph_len)
+            .ok_or(Error::Format("truncated PSF glyphs"))?;
+
+        let mut mapping = BTreeMap::new();
+        if has_table {
+            let table = &data[offset + count * glyph_len..];
+            let psf1 = data[0] == 0x36;
+            let mut entries = Vec::with_capacity(count);
+            if psf1 {
+                let mut entry = Vec::new();
+                for pair in table.chunks_exact(2) {
+                    match u16::from_le_bytes([pair[0], pair[1]]) {
+                        0xffff => entries.push(std::mem::take(&mut entry)),
+                        code => entry.push(code as u32),
+                    }
+                }
+            } else {
+                for entry in table.split(|b| *b == 0xff) {
+                    let single = entry.split(|b| *b == 0xfe).next().unwrap_or_default();
+                    let codes = String::from_utf8_lossy(single);
+                    entries.push(codes.chars().map(u32::from).collect());
+                }
+            }
+            for (idx, codes) in entries.into_iter().take(count).enumerate() {
+                for ch in codes.into_iter().filter_map(char::from_u32) {
+                    mapping.entry(ch).or_insert(idx);
+                }
+            }
+        } else {
+            for idx in 0..count {
+                if let Some(ch) = char::from_u32(idx as u32) {
+                    mapping.insert(ch, idx);
+                }
+            }
+        }
+
+        let glyphs = mapping
+            .into_iter()
+            .map(|(ch, idx)| {
+                let rows = &raster[idx * glyph_len..];
+                let mut glyph = Bitmap::new(width, height);
+                for y in 0..height {
+                    for x in 0..width {
+                        let byte = rows[y * stride + x / 8];
+                        glyph.set_pixel(x, y, byte & (0x80 >> (x % 8)) != 0);
+                    }
+                }
+                (ch, glyph)
+            })
+            .collect();
+
+        Ok(Self {
+            width,
+            height,
+            glyphs,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn glyph(&self, ch: char) -> Option<&Bitmap> {
+        self.glyphs.get(&ch)
+    }
+
+    pub fn sprite(&self, charset: &str) -> Result<(Sprite, Vec<u8>), Error> {
+        let mut alphabet = Vec::new();
+        let mut strip = Bitmap::new(self.width * charset.chars().count(), self.height);
+        for (idx, ch) in charset.chars().enumerate() {
+            let code = u8::try_from(u32::from(ch))
+                .map_err(|_| Error::Geometry("charset symbol does not fit a glyph"))?;
+            let glyph = self.glyph(ch).ok_or(Error::MissingGlyph(ch))?;
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    strip.set_pixel(idx * self.width + x, y, glyph.pixel(x, y));
+                }
+            }
+            alphabet.push(code);
+        }
+        let sprite = Sprite::from_strip(&strip, alphabet.len())?;
+        Ok((sprite, alphabet))
+    }
+}
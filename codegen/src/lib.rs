@@ -0,0 +1,38 @@
+mod bitmap;
+mod font;
+mod pack;
+mod rle;
+mod sprite;
+
+pub use bitmap::*;
+pub use font::*;
+pub use pack::*;
+pub use rle::*;
+pub use sprite::*;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Format(&'static str),
+    Geometry(&'static str),
+    MissingGlyph(char),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "IO error: {}", err),
+            Self::Format(msg) => write!(f, "Format error: {}", msg),
+            Self::Geometry(msg) => write!(f, "Geometry error: {}", msg),
+            Self::MissingGlyph(ch) => write!(f, "Glyph {:?} is missing in font", ch),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Error, Sprite};
+
+pub const PACK_MAGIC: [u8; 4] = *b"KLPK";
+pub const PACK_VERSION: u8 = 1;
+pub const PACK_HEADER_LEN: usize = 8;
+pub const PACK_ENTRY_LEN: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackGlyphs {
+    Single,
+    Sequential,
+    Alphabet(Vec<u8>),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SpritePack {
+    sprites: Vec<(u8, Sprite, PackGlyphs)>,
+}
+
+impl SpritePack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, id: u8, sprite: Sprite, glyphs: PackGlyphs) -> Result<(), Error> {
+        if self.sprites.len() >= u8::MAX as usize {
+            return Err(Error::Geometry("too many sprites in pack"));
+        }
+        if self
+            .sprites
+            .iter()
+            .any(|(sprite_id, _, _)| *sprite_id == id)
+        {
+            return Err(Error::Geometry("duplicate sprite id"));
+        }
+        if sprite.width > u16::MAX as usize || sprite.height > u16::MAX as usize {
+            return Err(Error::Geometry("sprite is too large"));
+        }
+        match &glyphs {
+            PackGlyphs::Single if sprite.glyphs != 1 => {
+                return Err(Error::Geometry("single glyph sprite has several glyphs"))
+            }
+            PackGlyphs::Sequential if sprite.glyphs > 256 => {
+                return Err(Error::Geometry("too many glyphs"))
+            }
+            PackGlyphs::Alphabet(alphabet) if alphabet.len() != sprite.glyphs => {
+                return Err(Error::Geometry("alphabet does not match glyph count"))
+            }
+            PackGlyphs::Alphabet(alphabet) if alphabet.len() >= 256 => {
+                return Err(Error::Geometry("alphabet is too long"))
+            }
+            _ => {}
+        }
+        self.sprites.push((id, sprite, glyphs));
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let tables_offset = PACK_HEADER_LEN + self.sprites.len() * PACK_ENTRY_LEN;
+        let tables = self
+            .sprites
+            .iter()
+            .filter(|(_, _, glyphs)| matches!(glyphs, PackGlyphs::Alphabet(_)))
+            .count();
+        let mut bitmap_offset = tables_offset + tables * 256;
+        let mut table_offset = tables_offset;
+
+        let mut header = Vec::with_capacity(bitmap_offset);
+        header.extend_from_slice(&PACK_MAGIC);
+        header.extend_from_slice(&[PACK_VERSION, self.sprites.len() as u8, 0, 0]);
+
+        let mut tables = Vec::new();
+        let mut bitmaps = Vec::new();
+        for (id, sprite, glyphs) in &self.sprites {
+            let (kind, table) = match glyphs {
+                PackGlyphs::Single => (0, 0),
+                PackGlyphs::Sequential => (1, 0),
+                PackGlyphs::Alphabet(alphabet) => {
+                    let mut index = [u8::MAX; 256];
+                    for (idx, glyph) in alphabet.iter().enumerate().rev() {
+                        index[*glyph as usize] = idx as u8;
+                    }
+                    tables.extend_from_slice(&index);
+                    table_offset += 256;
+                    (2, table_offset - 256)
+                }
+            };
+            header.extend_from_slice(&[*id, kind]);
+            header.extend_from_slice(&(sprite.glyphs as u16).to_le_bytes());
+            header.extend_from_slice(&(sprite.width as u16).to_le_bytes());
+            header.extend_from_slice(&(sprite.height as u16).to_le_bytes());
+            header.extend_from_slice(&(bitmap_offset as u32).to_le_bytes());
+            header.extend_from_slice(&(table as u32).to_le_bytes());
+            bitmaps.extend_from_slice(&sprite.bitmap);
+            bitmap_offset += sprite.bitmap.len();
+        }
+
+        header.extend(tables);
+        header.extend(bitmaps);
+        header
+    }
+}
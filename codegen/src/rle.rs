@@ -0,0 +1,35 @@
+pub fn rle_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut literal = Vec::new();
+    let mut pos = 0;
+
+    while pos < data.len() {
+        let run = data[pos..]
+            .iter()
+            .take(129)
+            .take_while(|byte| **byte == data[pos])
+            .count();
+        if run >= 3 || (run == 2 && literal.is_empty()) {
+            flush_literal(&mut out, &mut literal);
+            out.push(0x80 + (run - 2) as u8);
+            out.push(data[pos]);
+            pos += run;
+        } else {
+            literal.push(data[pos]);
+            if literal.len() == 128 {
+                flush_literal(&mut out, &mut literal);
+            }
+            pos += 1;
+        }
+    }
+    flush_literal(&mut out, &mut literal);
+
+    out
+}
+
+fn flush_literal(out: &mut Vec<u8>, literal: &mut Vec<u8>) {
+    if !literal.is_empty() {
+        out.push(literal.len() as u8 - 1);
+        out.append(literal);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{rle_encode, Bitmap, Error};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sprite {
+    pub width: usize,
+    pub height: usize,
+    pub glyphs: usize,
+    pub bitmap: Vec<u8>,
+}
+
+impl Sprite {
+    pub fn from_strip(strip: &Bitmap, glyphs: usize) -> Result<Self, Error> {
+        if glyphs == 0 {
+            return Err(Error::Geometry("sprite must have at least one glyph"));
+        }
+        if !strip.width().is_multiple_of(glyphs) {
+            return Err(Error::Geometry(
+                "strip width is not divisible by glyph count",
+            ));
+        }
+        let width = strip.width() / glyphs;
+        let pages = strip.height().div_ceil(8);
+        if width == 0 || pages == 0 {
+            return Err(Error::Geometry("empty sprite"));
+        }
+
+        let mut bitmap = Vec::with_capacity(glyphs * pages * width);
+        for glyph in 0..glyphs {
+            for page in 0..pages {
+                for col in 0..width {
+                    let x = glyph * width + col;
+                    let byte = (0..8).fold(0, |byte, bit| {
+                        if strip.pixel(x, page * 8 + bit) {
+                            byte | (1 << bit)
+                        } else {
+                            byte
+                        }
+                    });
+                    bitmap.push(byte);
+                }
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: pages * 8,
+            glyphs,
+            bitmap,
+        })
+    }
+
+    pub fn glyph_len(&self) -> usize {
+        self.width * self.height / 8
+    }
+
+    pub fn compress(&self) -> Option<(Vec<u16>, Vec<u8>)> {
+        let mut offsets = Vec::with_capacity(self.glyphs);
+        let mut data = Vec::new();
+        for glyph in self.bitmap.chunks(self.glyph_len()) {
+            offsets.push(u16::try_from(data.len()).ok()?);
+            data.extend(rle_encode(glyph));
+        }
+        (data.len() + offsets.len() * 2 < self.bitmap.len()).then_some((offsets, data))
+    }
+}
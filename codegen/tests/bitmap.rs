@@ -0,0 +1,107 @@
+use klaptik_codegen::{Bitmap, Error};
+
+fn rows(bitmap: &Bitmap) -> Vec<String> {
+    (0..bitmap.height())
+        .map(|y| {
+            (0..bitmap.width())
+                .map(|x| if bitmap.pixel(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+fn bmp(width: i32, height: i32, bpp: u16, palette: &[[u8; 4]], raster: &[u8]) -> Vec<u8> {
+    let offset = 14 + 40 + palette.len() * 4;
+    let mut data = Vec::new();
+    data.extend_from_slice(b"BM");
+    data.extend_from_slice(&((offset + raster.len()) as u32).to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&(offset as u32).to_le_bytes());
+    data.extend_from_slice(&40u32.to_le_bytes());
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&bpp.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&(raster.len() as u32).to_le_bytes());
+    data.extend_from_slice(&[0; 8]);
+    data.extend_from_slice(&(palette.len() as u32).to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    for color in palette {
+        data.extend_from_slice(color);
+    }
+    data.extend_from_slice(raster);
+    data
+}
+
+const BLACK: [u8; 4] = [0, 0, 0, 0];
+const WHITE: [u8; 4] = [0xff, 0xff, 0xff, 0];
+
+#[test]
+fn plain_pbm() {
+    let bitmap = Bitmap::from_pbm(b"P1\n# comment\n3 2\n1 0 1\n0 0 1 # trailing\n").unwrap();
+    assert_eq!(rows(&bitmap), ["#.#", "..#"]);
+}
+
+#[test]
+fn raw_pbm() {
+    let bitmap = Bitmap::from_pbm(b"P4\n10 2\n\xc0\x40\x01\x80").unwrap();
+    assert_eq!(rows(&bitmap), ["##.......#", ".......##."]);
+}
+
+#[test]
+fn malformed_pbm() {
+    assert!(matches!(
+        Bitmap::from_pbm(b"P1\n2 2\n1 0 1"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Bitmap::from_pbm(b"P4\n16 2\n\xff"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(Bitmap::from_pbm(b"P4 8 8"), Err(Error::Format(_))));
+}
+
+#[test]
+fn bottom_up_mono_bmp() {
+    let raster = [0xc0, 0, 0, 0, 0x40, 0, 0, 0];
+    let bitmap = Bitmap::from_bmp(&bmp(3, 2, 1, &[BLACK, WHITE], &raster)).unwrap();
+    assert_eq!(rows(&bitmap), ["#.#", "..#"]);
+}
+
+#[test]
+fn paletted_bmp() {
+    let raster = [0x01, 0x20, 0, 0, 0x12, 0x00, 0, 0];
+    let palette = [WHITE, BLACK, [0x40, 0x40, 0x40, 0]];
+    let bitmap = Bitmap::from_bmp(&bmp(4, 2, 4, &palette, &raster)).unwrap();
+    assert_eq!(rows(&bitmap), ["##..", ".##."]);
+}
+
+#[test]
+fn top_down_true_color_bmp() {
+    let raster = [
+        0, 0, 0, 0xff, 0xff, 0xff, 0, 0, 0x80, 0x80, 0x80, 0x20, 0x20, 0x20, 0, 0,
+    ];
+    let bitmap = Bitmap::from_bmp(&bmp(2, -2, 24, &[], &raster)).unwrap();
+    assert_eq!(rows(&bitmap), ["#.", ".#"]);
+}
+
+#[test]
+fn malformed_bmp() {
+    assert!(matches!(
+        Bitmap::from_bmp(b"PK\x03\x04"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Bitmap::from_bmp(&bmp(2, 2, 16, &[], &[0; 8])),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Bitmap::from_bmp(&bmp(2, 2, 1, &[BLACK, WHITE], &[0; 4])),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Bitmap::from_bmp(&bmp(0, 2, 1, &[BLACK, WHITE], &[0; 8])),
+        Err(Error::Geometry(_))
+    ));
+}
@@ -0,0 +1,115 @@
+use klaptik_codegen::{Error, Font};
+
+fn rows(font: &Font, ch: char) -> Vec<String> {
+    let glyph = font.glyph(ch).unwrap();
+    (0..glyph.height())
+        .map(|y| {
+            (0..glyph.width())
+                .map(|x| if glyph.pixel(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+const BDF: &str = "STARTFONT 2.1
+FONTBOUNDINGBOX 4 6 0 -1
+CHARS 2
+STARTCHAR A
+ENCODING 65
+BBX 3 3 1 0
+BITMAP
+E0
+A0
+E0
+ENDCHAR
+STARTCHAR underscore
+ENCODING 95
+BITMAP
+00
+00
+00
+00
+00
+F0
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn bdf_glyphs_are_placed_in_bounding_box() {
+    let font = Font::from_bdf(BDF).unwrap();
+    assert_eq!((font.width(), font.height()), (4, 6));
+    assert_eq!(
+        rows(&font, 'A'),
+        ["....", "....", ".###", ".#.#", ".###", "...."]
+    );
+    assert_eq!(
+        rows(&font, '_'),
+        ["....", "....", "....", "....", "....", "####"]
+    );
+    assert!(font.glyph('B').is_none());
+}
+
+#[test]
+fn malformed_bdf() {
+    assert!(matches!(
+        Font::from_bdf("STARTFONT 2.1\nSTARTCHAR A\n"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Font::from_bdf(&BDF.replacen("ENDCHAR", "", 1)),
+        Err(Error::Format(_))
+    ));
+}
+
+#[test]
+fn font_sprite_uses_charset_order() {
+    let font = Font::from_bdf(BDF).unwrap();
+    let (sprite, alphabet) = font.sprite("_A").unwrap();
+    assert_eq!(alphabet, b"_A");
+    assert_eq!((sprite.width, sprite.height, sprite.glyphs), (4, 8, 2));
+    assert_eq!(sprite.bitmap, [0x20, 0x20, 0x20, 0x20, 0, 0x1c, 0x14, 0x1c]);
+    assert!(matches!(font.sprite("AB"), Err(Error::MissingGlyph('B'))));
+}
+
+#[test]
+fn psf1_glyphs_follow_code_points() {
+    let mut data = vec![0x36, 0x04, 0x00, 2];
+    data.resize(4 + 256 * 2, 0);
+    data[4 + 65 * 2..][..2].copy_from_slice(&[0x80, 0x01]);
+
+    let font = Font::from_psf(&data).unwrap();
+    assert_eq!((font.width(), font.height()), (8, 2));
+    assert_eq!(rows(&font, 'A'), ["#.......", ".......#"]);
+    assert_eq!(rows(&font, 'B'), ["........", "........"]);
+}
+
+#[test]
+fn psf2_glyphs_follow_unicode_table() {
+    let mut data = vec![0x72, 0xb5, 0x4a, 0x86];
+    for field in [0u32, 32, 1, 2, 2, 2, 5] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(&[0xf8, 0x00, 0x88, 0x70]);
+    data.extend_from_slice(b"x\xff");
+    data.extend_from_slice("é".as_bytes());
+    data.extend_from_slice(b"\xfee\xcc\x81\xff");
+
+    let font = Font::from_psf(&data).unwrap();
+    assert_eq!((font.width(), font.height()), (5, 2));
+    assert_eq!(rows(&font, 'x'), ["#####", "....."]);
+    assert_eq!(rows(&font, 'é'), ["#...#", ".###."]);
+    assert!(font.glyph('\0').is_none());
+}
+
+#[test]
+fn malformed_psf() {
+    assert!(matches!(
+        Font::from_psf(b"\x00\x00\x00\x00"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        Font::from_psf(&[0x36, 0x04, 0x00, 8, 0, 0]),
+        Err(Error::Format(_))
+    ));
+}
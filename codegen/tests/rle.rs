@@ -0,0 +1,41 @@
+use klaptik_codegen::{rle_encode, Sprite};
+
+#[test]
+fn literals_and_runs() {
+    assert_eq!(rle_encode(&[]), []);
+    assert_eq!(rle_encode(&[1, 2, 3]), [0x02, 1, 2, 3]);
+    assert_eq!(rle_encode(&[5; 4]), [0x82, 5]);
+    assert_eq!(rle_encode(&[7, 7]), [0x80, 7]);
+    assert_eq!(rle_encode(&[1, 2, 2, 2, 3]), [0x00, 1, 0x81, 2, 0x00, 3]);
+}
+
+#[test]
+fn long_runs_and_literals_are_split() {
+    assert_eq!(rle_encode(&[9; 200]), [0xff, 9, 0xc5, 9]);
+
+    let literal: Vec<u8> = (0..130).map(|idx| idx as u8).collect();
+    let encoded = rle_encode(&literal);
+    assert_eq!(encoded.len(), 132);
+    assert_eq!(encoded[0], 0x7f);
+    assert_eq!(encoded[129], 0x01);
+    assert_eq!(&encoded[130..], [128, 129]);
+}
+
+#[test]
+fn sprite_compression_is_opportunistic() {
+    let blank = Sprite {
+        width: 8,
+        height: 8,
+        glyphs: 2,
+        bitmap: vec![0; 16],
+    };
+    let (offsets, data) = blank.compress().unwrap();
+    assert_eq!(offsets, [0, 2]);
+    assert_eq!(data, [0x86, 0, 0x86, 0]);
+
+    let noise = Sprite {
+        bitmap: (0..16).map(|idx| idx as u8).collect(),
+        ..blank
+    };
+    assert!(noise.compress().is_none());
+}
@@ -0,0 +1,128 @@
+use klaptik_codegen::*;
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span};
+use quote::quote;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Expr, LitInt, LitStr, Token};
+
+struct IncludeSprite {
+    id: Expr,
+    path: LitStr,
+    glyphs: usize,
+}
+
+impl Parse for IncludeSprite {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let id = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+        let mut glyphs = 1;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            glyphs = input.parse::<LitInt>()?.base10_parse()?;
+        }
+        Ok(Self { id, path, glyphs })
+    }
+}
+
+struct IncludeFont {
+    id: Expr,
+    path: LitStr,
+    charset: LitStr,
+}
+
+impl Parse for IncludeFont {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let id = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let charset = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { id, path, charset })
+    }
+}
+
+#[proc_macro]
+pub fn include_sprite(input: TokenStream) -> TokenStream {
+    let IncludeSprite { id, path, glyphs } = parse_macro_input!(input as IncludeSprite);
+    let file = resolve(&path);
+    match Bitmap::load(&file).and_then(|bitmap| Sprite::from_strip(&bitmap, glyphs)) {
+        Ok(sprite) => flash_sprite(&id, &sprite, &file).into(),
+        Err(err) => error(path.span(), &file, err).into(),
+    }
+}
+
+#[proc_macro]
+pub fn include_font(input: TokenStream) -> TokenStream {
+    let IncludeFont { id, path, charset } = parse_macro_input!(input as IncludeFont);
+    let file = resolve(&path);
+    match Font::load(&file).and_then(|font| font.sprite(&charset.value())) {
+        Ok((sprite, alphabet)) => {
+            let sprite = flash_sprite(&id, &sprite, &file);
+            let alphabet = alphabet.iter().map(|glyph| Literal::u8_unsuffixed(*glyph));
+            quote! {
+                (
+                    #sprite,
+                    ::klaptik::Glyphs::Indexed({
+                        const INDEX: ::klaptik::GlyphIndex =
+                            ::klaptik::GlyphIndex::new(&[#(#alphabet),*]);
+                        &INDEX
+                    }),
+                )
+            }
+            .into()
+        }
+        Err(err) => error(path.span(), &file, err).into(),
+    }
+}
+
+fn resolve(path: &LitStr) -> PathBuf {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    PathBuf::from(root).join(path.value())
+}
+
+fn error(span: Span, file: &std::path::Path, err: Error) -> proc_macro2::TokenStream {
+    let msg = format!("{}: {}", file.display(), err);
+    syn::Error::new(span, msg).to_compile_error()
+}
+
+fn flash_sprite(id: &Expr, sprite: &Sprite, file: &std::path::Path) -> proc_macro2::TokenStream {
+    let file = file.to_string_lossy();
+    let glyphs = Literal::usize_unsuffixed(sprite.glyphs);
+    let width = Literal::usize_unsuffixed(sprite.width);
+    let height = Literal::usize_unsuffixed(sprite.height);
+    if let Some((offsets, bitmap)) = sprite.compress() {
+        let offsets = offsets
+            .iter()
+            .map(|offset| Literal::u16_unsuffixed(*offset));
+        let bitmap = bitmap.iter().map(|byte| Literal::u8_unsuffixed(*byte));
+        return quote! {
+            {
+                const _: &[u8] = include_bytes!(#file);
+                ::klaptik::FlashSprite::rle(
+                    #id,
+                    #glyphs,
+                    ::klaptik::Size::new(#width, #height),
+                    &[#(#offsets),*],
+                    &[#(#bitmap),*],
+                )
+            }
+        };
+    }
+    let bitmap = sprite
+        .bitmap
+        .iter()
+        .map(|byte| Literal::u8_unsuffixed(*byte));
+    quote! {
+        {
+            const _: &[u8] = include_bytes!(#file);
+            ::klaptik::FlashSprite::new(
+                #id,
+                #glyphs,
+                ::klaptik::Size::new(#width, #height),
+                &[#(#bitmap),*],
+            )
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::*;
+
+const SCRATCH_LEN: usize = 128;
+
+pub struct AttributeCanvas<'a, C> {
+    canvas: &'a mut C,
+    sprite: Rectangle,
+    attributes: Attributes,
+}
+
+impl<'a, C: TryCanvas> AttributeCanvas<'a, C> {
+    pub fn new(canvas: &'a mut C, sprite: Rectangle, attributes: Attributes) -> Self {
+        Self {
+            canvas,
+            sprite,
+            attributes,
+        }
+    }
+
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    fn transform(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        gray: Option<(BitDepth, Style)>,
+    ) -> Result<(), C::Error> {
+        let attrs = self.attributes;
+        let depth = gray.map_or(BitDepth::Mono, |(depth, _)| depth);
+        let bits = depth.bits();
+        let scale = attrs.scale();
+        let width = bounds.size.width as usize;
+        let height = bounds.size.height as usize;
+        let plane = depth.plane_len(bounds.size);
+        if bitmap.len() < plane * bits {
+            return Ok(());
+        }
+
+        let origin = self.sprite.origin;
+        let sprite_width = self.sprite.size.width as usize;
+        let sprite_height = self.sprite.size.height as usize;
+        let x_rel = (bounds.origin.x as usize).saturating_sub(origin.x as usize);
+        let y_rel = (bounds.origin.y as usize).saturating_sub(origin.y as usize);
+        let chunk_width = (SCRATCH_LEN / (bits * scale * scale)).max(1);
+        let mut scratch = [0; SCRATCH_LEN];
+
+        for page in 0..height.div_ceil(8) {
+            let rows = (height - (page << 3)).min(8);
+            let out_pages = (rows * scale).div_ceil(8);
+            let mut col = 0;
+            while col < width {
+                let cols = chunk_width.min(width - col);
+                let out_width = cols * scale;
+
+                for bit in 0..bits {
+                    for out_col in 0..out_width {
+                        let src_col = if attrs.contains(Attributes::FLIP_H) {
+                            col + cols - 1 - out_col / scale
+                        } else {
+                            col + out_col / scale
+                        };
+                        let mut byte = bitmap[bit * plane + page * width + src_col];
+                        if attrs.contains(Attributes::INVERT) {
+                            byte = !byte;
+                        }
+                        if attrs.contains(Attributes::FLIP_V) {
+                            byte = byte.reverse_bits() >> (8 - rows);
+                        }
+                        for out_page in 0..out_pages {
+                            let out = if scale == 2 {
+                                stretch(byte >> (out_page << 2))
+                            } else {
+                                byte
+                            };
+                            scratch[(bit * out_pages + out_page) * out_width + out_col] = out;
+                        }
+                    }
+                }
+
+                let mut x = x_rel + col;
+                if attrs.contains(Attributes::FLIP_H) {
+                    x = sprite_width.saturating_sub(x + cols);
+                }
+                let mut y = y_rel + (page << 3);
+                if attrs.contains(Attributes::FLIP_V) {
+                    y = sprite_height.saturating_sub(y + rows);
+                }
+                let (x, y) = (origin.x as usize + x * scale, origin.y as usize + y * scale);
+                if x > Coord::MAX as usize || y > Coord::MAX as usize {
+                    col += cols;
+                    continue;
+                }
+                let area = Rectangle::new(
+                    Point::new(x as Coord, y as Coord),
+                    Size::new(out_width as Coord, (rows * scale) as Coord),
+                );
+                let data = &scratch[..(bits * out_pages * out_width)];
+                match gray {
+                    Some((depth, style)) => self.canvas.try_draw_gray(area, data, depth, style)?,
+                    None => self.canvas.try_draw(area, data)?,
+                }
+                col += cols;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: TryCanvas> TryCanvas for AttributeCanvas<'_, C> {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.transform(bounds, bitmap, None)
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        self.transform(bounds, bitmap, Some((depth, style)))
+    }
+}
+
+fn stretch(nibble: u8) -> u8 {
+    (0..4).fold(0, |byte, bit| {
+        if nibble & (1 << bit) != 0 {
+            byte | (3 << (bit << 1))
+        } else {
+            byte
+        }
+    })
+}
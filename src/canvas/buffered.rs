@@ -0,0 +1,94 @@
+use crate::*;
+use core::convert::Infallible;
+
+pub struct BufferedCanvas<C, const W: usize, const PAGES: usize> {
+    canvas: C,
+    back: FrameBuffer<W, PAGES>,
+    front: [[u8; W]; PAGES],
+    merge_gap: usize,
+    stale: bool,
+}
+
+impl<C, const W: usize, const PAGES: usize> BufferedCanvas<C, W, PAGES> {
+    pub const fn new(canvas: C) -> Self {
+        Self {
+            canvas,
+            back: FrameBuffer::new(),
+            front: [[0; W]; PAGES],
+            merge_gap: 4,
+            stale: true,
+        }
+    }
+
+    pub fn canvas(&mut self) -> &mut C {
+        &mut self.canvas
+    }
+
+    pub fn release(self) -> C {
+        self.canvas
+    }
+
+    pub fn back(&mut self) -> &mut FrameBuffer<W, PAGES> {
+        &mut self.back
+    }
+
+    pub fn front(&self) -> &[[u8; W]; PAGES] {
+        &self.front
+    }
+
+    pub fn set_merge_gap(&mut self, merge_gap: usize) {
+        self.merge_gap = merge_gap;
+    }
+
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.stale || self.back.pages() != &self.front
+    }
+}
+
+impl<C: TryCanvas, const W: usize, const PAGES: usize> BufferedCanvas<C, W, PAGES> {
+    pub fn flush(&mut self) -> Result<(), C::Error> {
+        let stale = self.stale;
+        for page in 0..PAGES {
+            let back = self.back.page(page);
+            let front = &mut self.front[page];
+            let mut col = 0;
+            while col < W {
+                if !stale && back[col] == front[col] {
+                    col += 1;
+                    continue;
+                }
+                let start = col;
+                let mut end = col + 1;
+                col += 1;
+                while col < W && col - end <= self.merge_gap {
+                    if stale || back[col] != front[col] {
+                        end = col + 1;
+                    }
+                    col += 1;
+                }
+                col = end;
+
+                let bounds = Rectangle::new(
+                    Point::new(start as Coord, (page << 3) as Coord),
+                    Size::new((end - start) as Coord, 8),
+                );
+                self.canvas.try_draw(bounds, &back[start..end])?;
+                front[start..end].copy_from_slice(&back[start..end]);
+            }
+        }
+        self.stale = false;
+        Ok(())
+    }
+}
+
+impl<C, const W: usize, const PAGES: usize> TryCanvas for BufferedCanvas<C, W, PAGES> {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.back.try_draw(bounds, bitmap)
+    }
+}
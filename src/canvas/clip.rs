@@ -0,0 +1,148 @@
+use crate::*;
+
+pub struct ClipCanvas<C> {
+    canvas: C,
+    size: Size,
+}
+
+impl<C> ClipCanvas<C> {
+    pub const fn new(canvas: C, size: Size) -> Self {
+        Self { canvas, size }
+    }
+
+    pub fn canvas(&mut self) -> &mut C {
+        &mut self.canvas
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn release(self) -> C {
+        self.canvas
+    }
+}
+
+impl<C: TryCanvas> TryCanvas for ClipCanvas<C> {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let screen = Rectangle::new(Point::zero(), self.size);
+        draw_clipped(&mut self.canvas, screen, bounds, bitmap, None)
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        let screen = Rectangle::new(Point::zero(), self.size);
+        draw_clipped(
+            &mut self.canvas,
+            screen,
+            bounds,
+            bitmap,
+            Some((depth, style)),
+        )
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.canvas.try_scroll(start_line)
+    }
+}
+
+const CLIP_SCRATCH: usize = 128;
+
+pub(crate) fn draw_clipped<C: TryCanvas + ?Sized>(
+    canvas: &mut C,
+    area: Rectangle,
+    bounds: Rectangle,
+    bitmap: &[u8],
+    gray: Option<(BitDepth, Style)>,
+) -> Result<(), C::Error> {
+    let visible = match bounds.intersect(&area) {
+        Some(visible) => visible,
+        None => return Ok(()),
+    };
+    let draw = |canvas: &mut C, origin: Point, size: Size, data: &[u8]| {
+        let bounds = Rectangle::new(
+            Point::new(origin.x - area.origin.x, origin.y - area.origin.y),
+            size,
+        );
+        match gray {
+            Some((depth, style)) => canvas.try_draw_gray(bounds, data, depth, style),
+            None => canvas.try_draw(bounds, data),
+        }
+    };
+    if visible == bounds {
+        return draw(canvas, bounds.origin, bounds.size, bitmap);
+    }
+
+    let depth = gray.map_or(BitDepth::Mono, |(depth, _)| depth);
+    let bits = depth.bits();
+    let plane = depth.plane_len(bounds.size);
+    if bitmap.len() < plane * bits {
+        return Ok(());
+    }
+
+    let width = bounds.size.width as usize;
+    let pages = plane / width;
+    let col_start = (visible.origin.x - bounds.origin.x) as usize;
+    let col_end = col_start + visible.size.width as usize;
+    let row_start = (visible.origin.y - bounds.origin.y) as usize;
+    let row_end = row_start + visible.size.height as usize;
+    let shift = row_start & 7;
+
+    let mut scratch = [0; CLIP_SCRATCH];
+    let chunk_width = CLIP_SCRATCH / bits;
+    let mut row = row_start;
+    while row < row_end {
+        let page = row >> 3;
+        let rows = (row_end - row).min(8);
+        let mask = (0xff_u16 >> (8 - rows)) as u8;
+        let y = visible.origin.y + (row - row_start) as Coord;
+
+        if bits == 1 && shift == 0 && rows == 8 {
+            let offset = page * width;
+            let chunk = &bitmap[(offset + col_start)..(offset + col_end)];
+            draw(
+                canvas,
+                Point::new(visible.origin.x, y),
+                Size::new(visible.size.width, 8),
+                chunk,
+            )?;
+            row += rows;
+            continue;
+        }
+
+        let mut col = col_start;
+        while col < col_end {
+            let cols = chunk_width.min(col_end - col);
+            for bit in 0..bits {
+                let src = &bitmap[(bit * plane)..((bit + 1) * plane)];
+                for (idx, byte) in scratch[(bit * cols)..((bit + 1) * cols)]
+                    .iter_mut()
+                    .enumerate()
+                {
+                    let offset = page * width + col + idx;
+                    let lo = src[offset] >> shift;
+                    let hi = match shift {
+                        0 => 0,
+                        _ if page + 1 < pages => src[offset + width] << (8 - shift),
+                        _ => 0,
+                    };
+                    *byte = (lo | hi) & mask;
+                }
+            }
+            let x = visible.origin.x + (col - col_start) as Coord;
+            let size = Size::new(cols as Coord, rows as Coord);
+            draw(canvas, Point::new(x, y), size, &scratch[..(bits * cols)])?;
+            col += cols;
+        }
+        row += rows;
+    }
+
+    Ok(())
+}
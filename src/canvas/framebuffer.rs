@@ -0,0 +1,144 @@
+use crate::*;
+use core::convert::Infallible;
+use core::fmt;
+
+pub struct FrameBuffer<const W: usize, const PAGES: usize> {
+    pages: [[u8; W]; PAGES],
+}
+
+impl<const W: usize, const PAGES: usize> FrameBuffer<W, PAGES> {
+    pub const WIDTH: usize = W;
+    pub const HEIGHT: usize = PAGES * 8;
+
+    pub const fn new() -> Self {
+        Self {
+            pages: [[0; W]; PAGES],
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(W as _, Self::HEIGHT as _)
+    }
+
+    pub fn clear(&mut self) {
+        self.fill(false);
+    }
+
+    pub fn fill(&mut self, on: bool) {
+        let val = if on { 0xff } else { 0 };
+        for page in self.pages.iter_mut() {
+            page.fill(val);
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        x < W && y < Self::HEIGHT && self.pages[y >> 3][x] & (1 << (y & 7)) != 0
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        if x >= W || y >= Self::HEIGHT {
+            return;
+        }
+        let mask = 1 << (y & 7);
+        if on {
+            self.pages[y >> 3][x] |= mask;
+        } else {
+            self.pages[y >> 3][x] &= !mask;
+        }
+    }
+
+    pub fn page(&self, page: usize) -> &[u8; W] {
+        &self.pages[page]
+    }
+
+    pub fn pages(&self) -> &[[u8; W]; PAGES] {
+        &self.pages
+    }
+
+    pub fn ascii(&self) -> AsciiArt<'_, W, PAGES> {
+        AsciiArt(self)
+    }
+
+    pub fn pbm(&self) -> Pbm<'_, W, PAGES> {
+        Pbm(self)
+    }
+}
+
+impl<const W: usize, const PAGES: usize> Default for FrameBuffer<W, PAGES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const PAGES: usize> TryCanvas for FrameBuffer<W, PAGES> {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        if bounds.is_empty() {
+            return Ok(());
+        }
+
+        let x = bounds.origin.x as usize;
+        let y = bounds.origin.y as usize;
+        let width = bounds.size.width as usize;
+        let height = bounds.size.height as usize;
+
+        if y & 7 == 0 && height & 7 == 0 {
+            let first_page = y >> 3;
+            for (chunk, row) in bitmap.chunks(width).take(height >> 3).enumerate() {
+                if let Some(page) = self.pages.get_mut(first_page + chunk) {
+                    for (col, byte) in row.iter().enumerate() {
+                        if let Some(dst) = page.get_mut(x + col) {
+                            *dst = *byte;
+                        }
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        for row in 0..height {
+            let offset = (row >> 3) * width;
+            let mask = 1 << (row & 7);
+            for col in 0..width {
+                if let Some(byte) = bitmap.get(offset + col) {
+                    self.set_pixel(x + col, y + row, byte & mask != 0);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct AsciiArt<'a, const W: usize, const PAGES: usize>(&'a FrameBuffer<W, PAGES>);
+
+impl<const W: usize, const PAGES: usize> fmt::Display for AsciiArt<'_, W, PAGES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..FrameBuffer::<W, PAGES>::HEIGHT {
+            for x in 0..W {
+                f.write_str(if self.0.pixel(x, y) { "#" } else { "." })?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Pbm<'a, const W: usize, const PAGES: usize>(&'a FrameBuffer<W, PAGES>);
+
+impl<const W: usize, const PAGES: usize> fmt::Display for Pbm<'_, W, PAGES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "P1")?;
+        writeln!(f, "{} {}", W, FrameBuffer::<W, PAGES>::HEIGHT)?;
+        for y in 0..FrameBuffer::<W, PAGES>::HEIGHT {
+            for x in 0..W {
+                if x > 0 && x % 70 == 0 {
+                    f.write_str("\n")?;
+                }
+                f.write_str(if self.0.pixel(x, y) { "1" } else { "0" })?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::*;
+use core::convert::Infallible;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{self as eg, OriginDimensions};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives;
+use embedded_graphics_core::Pixel;
+
+impl From<Point> for eg::Point {
+    fn from(p: Point) -> Self {
+        eg::Point::new(p.x as _, p.y as _)
+    }
+}
+
+impl From<Size> for eg::Size {
+    fn from(size: Size) -> Self {
+        eg::Size::new(size.width as _, size.height as _)
+    }
+}
+
+impl From<Rectangle> for primitives::Rectangle {
+    fn from(rect: Rectangle) -> Self {
+        primitives::Rectangle::new(rect.origin.into(), rect.size.into())
+    }
+}
+
+pub struct GraphicsCanvas<T> {
+    target: T,
+}
+
+impl<T> GraphicsCanvas<T> {
+    pub const fn new(target: T) -> Self {
+        Self { target }
+    }
+
+    pub fn target(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    pub fn release(self) -> T {
+        self.target
+    }
+}
+
+impl<T: DrawTarget<Color = BinaryColor>> TryCanvas for GraphicsCanvas<T> {
+    type Error = T::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let width = bounds.size.width as usize;
+        let height = bounds.size.height as usize;
+        let colors = (0..height).flat_map(move |row| {
+            let offset = (row >> 3) * width;
+            let mask = 1 << (row & 7);
+            (0..width).map(move |col| match bitmap.get(offset + col) {
+                Some(byte) if byte & mask != 0 => BinaryColor::On,
+                _ => BinaryColor::Off,
+            })
+        });
+        self.target.fill_contiguous(&bounds.into(), colors)
+    }
+}
+
+impl<const W: usize, const PAGES: usize> OriginDimensions for FrameBuffer<W, PAGES> {
+    fn size(&self) -> eg::Size {
+        eg::Size::new(W as _, Self::HEIGHT as _)
+    }
+}
+
+impl<const W: usize, const PAGES: usize> DrawTarget for FrameBuffer<W, PAGES> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (usize::try_from(point.x), usize::try_from(point.y)) {
+                self.set_pixel(x, y, color.is_on());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::*;
+use core::convert::Infallible;
+use core::fmt;
+
+pub struct GrayFrameBuffer<const ROW: usize, const H: usize> {
+    rows: [[u8; ROW]; H],
+}
+
+impl<const ROW: usize, const H: usize> GrayFrameBuffer<ROW, H> {
+    pub const WIDTH: usize = ROW * 2;
+    pub const HEIGHT: usize = H;
+
+    pub const fn new() -> Self {
+        Self {
+            rows: [[0; ROW]; H],
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(Self::WIDTH as _, H as _)
+    }
+
+    pub fn clear(&mut self) {
+        self.fill(0);
+    }
+
+    pub fn fill(&mut self, level: GrayLevel) {
+        let level = level.min(MAX_GRAY_LEVEL);
+        for row in self.rows.iter_mut() {
+            row.fill(level << 4 | level);
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> GrayLevel {
+        if x >= Self::WIDTH || y >= H {
+            return 0;
+        }
+        let byte = self.rows[y][x >> 1];
+        if x & 1 == 0 {
+            byte >> 4
+        } else {
+            byte & 0x0f
+        }
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, level: GrayLevel) {
+        if x >= Self::WIDTH || y >= H {
+            return;
+        }
+        let level = level.min(MAX_GRAY_LEVEL);
+        let byte = &mut self.rows[y][x >> 1];
+        if x & 1 == 0 {
+            *byte = (*byte & 0x0f) | level << 4;
+        } else {
+            *byte = (*byte & 0xf0) | level;
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[u8; ROW] {
+        &self.rows[y]
+    }
+
+    pub fn rows(&self) -> &[[u8; ROW]; H] {
+        &self.rows
+    }
+
+    pub fn pgm(&self) -> Pgm<'_, ROW, H> {
+        Pgm(self)
+    }
+}
+
+impl<const ROW: usize, const H: usize> Default for GrayFrameBuffer<ROW, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ROW: usize, const H: usize> TryCanvas for GrayFrameBuffer<ROW, H> {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.try_draw_gray(bounds, bitmap, BitDepth::Mono, Style::default())
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        let x = bounds.origin.x as usize;
+        let y = bounds.origin.y as usize;
+        let level = style.level.min(MAX_GRAY_LEVEL) as u16;
+        for row in 0..bounds.size.height as usize {
+            for col in 0..bounds.size.width as usize {
+                let value = depth.level(bitmap, bounds.size, col, row) as u16;
+                let value = value * level / MAX_GRAY_LEVEL as u16;
+                self.set_pixel(x + col, y + row, value as GrayLevel);
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Pgm<'a, const ROW: usize, const H: usize>(&'a GrayFrameBuffer<ROW, H>);
+
+impl<const ROW: usize, const H: usize> fmt::Display for Pgm<'_, ROW, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "P2")?;
+        writeln!(f, "{} {}", GrayFrameBuffer::<ROW, H>::WIDTH, H)?;
+        writeln!(f, "{}", MAX_GRAY_LEVEL)?;
+        for y in 0..H {
+            for x in 0..GrayFrameBuffer::<ROW, H>::WIDTH {
+                if x > 0 && x % 24 == 0 {
+                    f.write_str("\n")?;
+                } else if x > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{}", self.0.pixel(x, y))?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+mod attributes;
+mod buffered;
+mod clip;
+mod framebuffer;
+#[cfg(feature = "embedded-graphics")]
+mod graphics;
+mod gray;
+mod rotate;
+mod shadow;
+mod split;
+
+pub use attributes::*;
+pub use buffered::*;
+pub use clip::*;
+pub use framebuffer::*;
+#[cfg(feature = "embedded-graphics")]
+pub use graphics::*;
+pub use gray::*;
+pub use rotate::*;
+pub use shadow::*;
+pub use split::*;
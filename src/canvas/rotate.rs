@@ -0,0 +1,210 @@
+use crate::*;
+
+const SCRATCH_LEN: usize = 128;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    pub const fn is_transposed(self) -> bool {
+        matches!(self, Rotation::Deg90 | Rotation::Deg270)
+    }
+}
+
+pub struct RotateCanvas<C> {
+    canvas: C,
+    panel: Size,
+    rotation: Rotation,
+    mirror: bool,
+}
+
+impl<C> RotateCanvas<C> {
+    pub const fn new(canvas: C, panel: Size, rotation: Rotation) -> Self {
+        Self {
+            canvas,
+            panel,
+            rotation,
+            mirror: false,
+        }
+    }
+
+    pub fn canvas(&mut self) -> &mut C {
+        &mut self.canvas
+    }
+
+    pub fn release(self) -> C {
+        self.canvas
+    }
+
+    pub fn panel(&self) -> Size {
+        self.panel
+    }
+
+    pub fn size(&self) -> Size {
+        if self.rotation.is_transposed() {
+            Size::new(self.panel.height, self.panel.width)
+        } else {
+            self.panel
+        }
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub fn mirror(&self) -> bool {
+        self.mirror
+    }
+
+    pub fn set_mirror(&mut self, mirror: bool) {
+        self.mirror = mirror;
+    }
+
+    fn map_point(&self, x: usize, y: usize) -> (usize, usize) {
+        let size = self.size();
+        let (width, height) = (size.width as usize, size.height as usize);
+        let (x, y) = match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (height - 1 - y, x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (y, width - 1 - x),
+        };
+        if self.mirror {
+            (self.panel.width as usize - 1 - x, y)
+        } else {
+            (x, y)
+        }
+    }
+
+    fn map_rect(&self, rect: Rectangle) -> Rectangle {
+        let (x0, y0) = self.map_point(rect.origin.x as usize, rect.origin.y as usize);
+        let (x1, y1) = self.map_point(
+            rect.origin.x as usize + rect.size.width as usize - 1,
+            rect.origin.y as usize + rect.size.height as usize - 1,
+        );
+        let size = if self.rotation.is_transposed() {
+            Size::new(rect.size.height, rect.size.width)
+        } else {
+            rect.size
+        };
+        Rectangle::new(Point::new(x0.min(x1) as Coord, y0.min(y1) as Coord), size)
+    }
+
+    fn source(&self, width: usize, height: usize, x: usize, y: usize) -> (usize, usize) {
+        let out_width = if self.rotation.is_transposed() {
+            height
+        } else {
+            width
+        };
+        let x = if self.mirror { out_width - 1 - x } else { x };
+        match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (y, height - 1 - x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (width - 1 - y, x),
+        }
+    }
+}
+
+impl<C: TryCanvas> RotateCanvas<C> {
+    fn transform(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        gray: Option<(BitDepth, Style)>,
+    ) -> Result<(), C::Error> {
+        let screen = Rectangle::new(Point::zero(), self.size());
+        let visible = match bounds.intersect(&screen) {
+            Some(visible) => visible,
+            None => return Ok(()),
+        };
+        if self.rotation == Rotation::Deg0 && !self.mirror && visible == bounds {
+            return match gray {
+                Some((depth, style)) => self.canvas.try_draw_gray(bounds, bitmap, depth, style),
+                None => self.canvas.try_draw(bounds, bitmap),
+            };
+        }
+
+        let depth = gray.map_or(BitDepth::Mono, |(depth, _)| depth);
+        let bits = depth.bits();
+        let plane = depth.plane_len(bounds.size);
+        if bitmap.len() < plane * bits {
+            return Ok(());
+        }
+
+        let src_width = bounds.size.width as usize;
+        let src_x = (visible.origin.x - bounds.origin.x) as usize;
+        let src_y = (visible.origin.y - bounds.origin.y) as usize;
+        let (vis_width, vis_height) = (visible.size.width as usize, visible.size.height as usize);
+        let area = self.map_rect(visible);
+        let (out_width, out_height) = (area.size.width as usize, area.size.height as usize);
+        let chunk_width = SCRATCH_LEN / bits;
+        let mut scratch = [0; SCRATCH_LEN];
+
+        let mut band = 0;
+        while band < out_height {
+            let rows = (out_height - band).min(8);
+            let mut col = 0;
+            while col < out_width {
+                let cols = chunk_width.min(out_width - col);
+                for bit in 0..bits {
+                    for out_col in 0..cols {
+                        let mut byte = 0;
+                        for row in 0..rows {
+                            let (x, y) =
+                                self.source(vis_width, vis_height, col + out_col, band + row);
+                            let (x, y) = (src_x + x, src_y + y);
+                            let offset = bit * plane + (y >> 3) * src_width + x;
+                            if bitmap[offset] & (1 << (y & 7)) != 0 {
+                                byte |= 1 << row;
+                            }
+                        }
+                        scratch[bit * cols + out_col] = byte;
+                    }
+                }
+                let piece = Rectangle::new(
+                    Point::new(area.origin.x + col as Coord, area.origin.y + band as Coord),
+                    Size::new(cols as Coord, rows as Coord),
+                );
+                let data = &scratch[..(bits * cols)];
+                match gray {
+                    Some((depth, style)) => self.canvas.try_draw_gray(piece, data, depth, style)?,
+                    None => self.canvas.try_draw(piece, data)?,
+                }
+                col += cols;
+            }
+            band += rows;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: TryCanvas> TryCanvas for RotateCanvas<C> {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.transform(bounds, bitmap, None)
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        self.transform(bounds, bitmap, Some((depth, style)))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::*;
+
+pub struct ShadowCanvas<C, const W: usize, const PAGES: usize> {
+    canvas: C,
+    shadow: FrameBuffer<W, PAGES>,
+}
+
+impl<C, const W: usize, const PAGES: usize> ShadowCanvas<C, W, PAGES> {
+    pub const fn new(canvas: C) -> Self {
+        Self {
+            canvas,
+            shadow: FrameBuffer::new(),
+        }
+    }
+
+    pub fn canvas(&mut self) -> &mut C {
+        &mut self.canvas
+    }
+
+    pub fn release(self) -> C {
+        self.canvas
+    }
+
+    pub fn shadow(&self) -> &FrameBuffer<W, PAGES> {
+        &self.shadow
+    }
+}
+
+impl<C: TryCanvas, const W: usize, const PAGES: usize> TryCanvas for ShadowCanvas<C, W, PAGES> {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let screen = Rectangle::new(Point::zero(), self.shadow.size());
+        let visible = match bounds.intersect(&screen) {
+            Some(visible) => visible,
+            None => return Ok(()),
+        };
+        self.shadow.try_draw(bounds, bitmap).ok();
+
+        let aligned = bounds.origin.y & 7 == 0 && bounds.size.height & 7 == 0;
+        if aligned && visible == bounds {
+            return self.canvas.try_draw(bounds, bitmap);
+        }
+
+        let start = visible.origin.x as usize;
+        let end = start + visible.size.width as usize;
+        let first_page = visible.origin.y as usize >> 3;
+        let last_page = (visible.origin.y as usize + visible.size.height as usize).div_ceil(8);
+        for page in first_page..last_page {
+            let area = Rectangle::new(
+                Point::new(start as Coord, (page << 3) as Coord),
+                Size::new((end - start) as Coord, 8),
+            );
+            self.canvas
+                .try_draw(area, &self.shadow.page(page)[start..end])?;
+        }
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.canvas.try_scroll(start_line)
+    }
+}
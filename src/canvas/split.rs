@@ -0,0 +1,48 @@
+use crate::canvas::clip::draw_clipped;
+use crate::*;
+
+pub struct SplitCanvas<C, const N: usize> {
+    regions: [(Rectangle, C); N],
+}
+
+impl<C, const N: usize> SplitCanvas<C, N> {
+    pub const fn new(regions: [(Rectangle, C); N]) -> Self {
+        Self { regions }
+    }
+
+    pub fn region(&mut self, idx: usize) -> Option<&mut C> {
+        self.regions.get_mut(idx).map(|(_, canvas)| canvas)
+    }
+
+    pub fn regions(&mut self) -> &mut [(Rectangle, C); N] {
+        &mut self.regions
+    }
+
+    pub fn release(self) -> [(Rectangle, C); N] {
+        self.regions
+    }
+}
+
+impl<C: TryCanvas, const N: usize> TryCanvas for SplitCanvas<C, N> {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        for (area, canvas) in self.regions.iter_mut() {
+            draw_clipped(canvas, *area, bounds, bitmap, None)?;
+        }
+        Ok(())
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        for (area, canvas) in self.regions.iter_mut() {
+            draw_clipped(canvas, *area, bounds, bitmap, Some((depth, style)))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+
+mod palette;
+mod queue;
+mod recording;
+mod viewport;
+
+pub use palette::*;
+pub use queue::*;
+pub use recording::*;
+pub use viewport::*;
+
+pub struct SpriteDisplay<C, S> {
+    canvas: C,
+    sprites: S,
+}
+
+impl<C, S> SpriteDisplay<C, S> {
+    pub const fn new(canvas: C, sprites: S) -> Self {
+        Self { canvas, sprites }
+    }
+
+    pub fn canvas(&mut self) -> &mut C {
+        &mut self.canvas
+    }
+
+    pub fn sprites(&mut self) -> &mut S {
+        &mut self.sprites
+    }
+}
+
+impl<C, S: SpriteStore> SpriteDisplay<C, S> {
+    pub fn take_invalidated(&mut self) -> Option<SpriteId> {
+        self.sprites.take_invalidated()
+    }
+}
+
+impl<C: TryCanvas, S: SpriteStore> TryDisplay for SpriteDisplay<C, S> {
+    type Error = RenderError<C::Error>;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        if req.attributes.is_empty() {
+            return self.sprites.draw(&mut self.canvas, req);
+        }
+        let size = match self.sprites.sprite_size(req.sprite_id) {
+            Some(size) => size,
+            None => return Ok(()),
+        };
+        let sprite = Rectangle::new(req.origin, size);
+        let mut canvas = AttributeCanvas::new(&mut self.canvas, sprite, req.attributes);
+        self.sprites.draw(&mut canvas, req)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.canvas
+            .try_scroll(start_line)
+            .map_err(RenderError::Canvas)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<A: TryDisplay, B: TryDisplay> TryDisplay for (A, B) {
+    type Error = Either<A::Error, B::Error>;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        let first = self.0.try_render(req).map_err(Either::Left);
+        let second = self.1.try_render(req).map_err(Either::Right);
+        first.and(second)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let first = self.0.try_scroll(start_line).map_err(Either::Left);
+        let second = self.1.try_scroll(start_line).map_err(Either::Right);
+        Ok(common_lines(first?, second?))
+    }
+}
+
+impl<A: TryDisplay, B: TryDisplay, C: TryDisplay> TryDisplay for (A, B, C) {
+    type Error = Either<A::Error, Either<B::Error, C::Error>>;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        let first = self.0.try_render(req).map_err(Either::Left);
+        let rest = (&mut self.1, &mut self.2)
+            .try_render(req)
+            .map_err(Either::Right);
+        first.and(rest)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let first = self.0.try_scroll(start_line).map_err(Either::Left);
+        let rest = (&mut self.1, &mut self.2)
+            .try_scroll(start_line)
+            .map_err(Either::Right);
+        Ok(common_lines(first?, rest?))
+    }
+}
+
+impl<D: TryDisplay + ?Sized> TryDisplay for &mut D {
+    type Error = D::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        (**self).try_render(req)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        (**self).try_scroll(start_line)
+    }
+}
+
+impl<D: TryDisplay> TryDisplay for [D] {
+    type Error = D::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        for display in self.iter_mut() {
+            display.try_render(req)?;
+        }
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let mut common = None;
+        for (idx, display) in self.iter_mut().enumerate() {
+            let lines = display.try_scroll(start_line)?;
+            common = if idx == 0 {
+                lines
+            } else {
+                common_lines(common, lines)
+            };
+        }
+        Ok(common)
+    }
+}
+
+impl<D: TryDisplay, const N: usize> TryDisplay for [D; N] {
+    type Error = D::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        self.as_mut_slice().try_render(req)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.as_mut_slice().try_scroll(start_line)
+    }
+}
+
+impl Display for [&mut dyn Display] {
+    fn render(&mut self, req: RenderRequest) {
+        for display in self.iter_mut() {
+            display.render(req);
+        }
+    }
+
+    fn scroll(&mut self, start_line: Coord) -> Option<Coord> {
+        self.iter_mut()
+            .map(|display| display.scroll(start_line))
+            .reduce(common_lines)
+            .flatten()
+    }
+}
+
+impl<const N: usize> Display for [&mut dyn Display; N] {
+    fn render(&mut self, req: RenderRequest) {
+        self.as_mut_slice().render(req)
+    }
+
+    fn scroll(&mut self, start_line: Coord) -> Option<Coord> {
+        self.as_mut_slice().scroll(start_line)
+    }
+}
+
+fn common_lines(lines: Option<Coord>, other: Option<Coord>) -> Option<Coord> {
+    lines.filter(|_| lines == other)
+}
@@ -0,0 +1,48 @@
+use crate::*;
+
+pub struct PaletteDisplay<D, const N: usize> {
+    display: D,
+    palettes: [(SpriteId, PaletteId); N],
+}
+
+impl<D, const N: usize> PaletteDisplay<D, N> {
+    pub const fn new(display: D, palettes: [(SpriteId, PaletteId); N]) -> Self {
+        Self { display, palettes }
+    }
+
+    pub fn display(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    pub fn release(self) -> D {
+        self.display
+    }
+
+    pub fn palette(&self, sprite_id: SpriteId) -> Option<PaletteId> {
+        self.palettes
+            .iter()
+            .find(|(id, _)| *id == sprite_id)
+            .map(|(_, palette)| *palette)
+    }
+
+    pub fn set_palette(&mut self, sprite_id: SpriteId, palette: PaletteId) {
+        if let Some(entry) = self.palettes.iter_mut().find(|(id, _)| *id == sprite_id) {
+            entry.1 = palette;
+        }
+    }
+}
+
+impl<D: TryDisplay, const N: usize> TryDisplay for PaletteDisplay<D, N> {
+    type Error = D::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        match self.palette(req.sprite_id) {
+            Some(palette) => self.display.try_render(req.with_palette(palette)),
+            None => self.display.try_render(req),
+        }
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.display.try_scroll(start_line)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueOverflow(pub RenderRequest);
+
+pub struct RenderQueue<D, const N: usize> {
+    display: D,
+    requests: [RenderRequest; N],
+    len: usize,
+}
+
+impl<D, const N: usize> RenderQueue<D, N> {
+    pub const fn new(display: D) -> Self {
+        Self {
+            display,
+            requests: [RenderRequest::new(Point::zero(), 0, 0); N],
+            len: 0,
+        }
+    }
+
+    pub fn display(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    pub fn release(self) -> D {
+        self.display
+    }
+
+    pub fn requests(&self) -> &[RenderRequest] {
+        &self.requests[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<D: TryDisplay, const N: usize> RenderQueue<D, N> {
+    pub fn flush(&mut self) -> Result<(), D::Error> {
+        for idx in 0..self.len {
+            if let Err(err) = self.display.try_render(self.requests[idx]) {
+                self.requests.copy_within(idx..self.len, 0);
+                self.len -= idx;
+                return Err(err);
+            }
+        }
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl<D: Display, const N: usize> TryDisplay for RenderQueue<D, N> {
+    type Error = QueueOverflow;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        let key = |req: &RenderRequest| (req.origin.y >> 3, req.origin.x, req.origin.y);
+        let queued = &mut self.requests[..self.len];
+        match queued.binary_search_by_key(&key(&req), key) {
+            Ok(idx) => queued[idx] = req,
+            Err(_) if self.len == N => return Err(QueueOverflow(req)),
+            Err(idx) => {
+                self.requests.copy_within(idx..self.len, idx + 1);
+                self.requests[idx] = req;
+                self.len += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        Ok(self.display.scroll(start_line))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::*;
+use core::convert::Infallible;
+
+pub struct RecordingDisplay<const N: usize> {
+    requests: [RenderRequest; N],
+    len: usize,
+    dropped: usize,
+}
+
+impl<const N: usize> RecordingDisplay<N> {
+    pub const fn new() -> Self {
+        Self {
+            requests: [RenderRequest::new(Point::zero(), 0, 0); N],
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    pub fn requests(&self) -> &[RenderRequest] {
+        &self.requests[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.dropped = 0;
+    }
+
+    pub fn last_at(&self, origin: Point) -> Option<RenderRequest> {
+        self.requests()
+            .iter()
+            .rev()
+            .find(|req| req.origin == origin)
+            .copied()
+    }
+
+    pub fn glyph_at(&self, origin: Point) -> Option<(SpriteId, Glyph)> {
+        self.last_at(origin).map(|req| (req.sprite_id, req.glyph))
+    }
+
+    pub fn redraws_at(&self, origin: Point) -> usize {
+        self.requests()
+            .iter()
+            .filter(|req| req.origin == origin)
+            .count()
+    }
+
+    pub fn redundant(&self) -> usize {
+        let requests = self.requests();
+        requests
+            .iter()
+            .enumerate()
+            .filter(|(idx, req)| {
+                requests[..*idx]
+                    .iter()
+                    .rev()
+                    .find(|prev| prev.origin == req.origin)
+                    .is_some_and(|prev| prev == *req)
+            })
+            .count()
+    }
+
+    #[track_caller]
+    pub fn assert_glyph_at(&self, origin: Point, sprite_id: SpriteId, glyph: Glyph) {
+        assert_eq!(
+            self.glyph_at(origin),
+            Some((sprite_id, glyph)),
+            "unexpected glyph at {:?}",
+            origin
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_redraws(&self, redraws: usize) {
+        assert_eq!(self.len + self.dropped, redraws, "unexpected redraw count");
+    }
+
+    #[track_caller]
+    pub fn assert_no_redundant(&self) {
+        assert_eq!(self.redundant(), 0, "redundant redraws recorded");
+    }
+}
+
+impl<const N: usize> Default for RecordingDisplay<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TryDisplay for RecordingDisplay<N> {
+    type Error = Infallible;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        match self.requests.get_mut(self.len) {
+            Some(slot) => {
+                *slot = req;
+                self.len += 1;
+            }
+            None => self.dropped += 1,
+        }
+        Ok(())
+    }
+}
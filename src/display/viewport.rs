@@ -0,0 +1,74 @@
+use crate::*;
+
+pub struct Viewport<D> {
+    display: D,
+    area: Rectangle,
+    shift: (i8, i8),
+}
+
+impl<D> Viewport<D> {
+    pub const fn new(display: D, area: Rectangle) -> Self {
+        Self {
+            display,
+            area,
+            shift: (0, 0),
+        }
+    }
+
+    pub fn display(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    pub fn release(self) -> D {
+        self.display
+    }
+
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+
+    pub fn set_area(&mut self, area: Rectangle) {
+        self.area = area;
+    }
+
+    pub fn set_origin(&mut self, origin: Point) {
+        self.area.origin = origin;
+    }
+
+    pub fn shift(&self) -> (i8, i8) {
+        self.shift
+    }
+
+    pub fn set_shift(&mut self, dx: i8, dy: i8) {
+        self.shift = (dx, dy);
+    }
+
+    pub fn translate(&self, point: Point) -> Option<Point> {
+        let local = Rectangle::new(Point::zero(), self.area.size);
+        if !local.contains(point) {
+            return None;
+        }
+        let x = self.area.origin.x as i32 + point.x as i32 + self.shift.0 as i32;
+        let y = self.area.origin.y as i32 + point.y as i32 + self.shift.1 as i32;
+        let max = Coord::MAX as i32;
+        Some(Point::new(
+            x.clamp(0, max) as Coord,
+            y.clamp(0, max) as Coord,
+        ))
+    }
+}
+
+impl<D: TryDisplay> TryDisplay for Viewport<D> {
+    type Error = D::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        match self.translate(req.origin) {
+            Some(origin) => self.display.try_render(RenderRequest { origin, ..req }),
+            None => Ok(()),
+        }
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.display.try_scroll(start_line)
+    }
+}
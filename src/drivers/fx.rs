@@ -7,16 +7,28 @@ pub enum FxCommand {
     UploadSprite = 0x81,
     DeleteSprite = 0x82,
     DeleteAllSprites = 0x83,
+    RenderExt = 0x84,
 }
 
 pub struct FxDisplay<L, const ADDR: usize, const N: usize> {
     link: L,
     sprite_map: [(SpriteId, Glyphs); N],
+    index: SpriteIndex,
 }
 
 impl<L, const ADDR: usize, const N: usize> FxDisplay<L, ADDR, N> {
     pub const fn new(link: L, sprite_map: [(SpriteId, Glyphs); N]) -> Self {
-        Self { link, sprite_map }
+        let mut index = SpriteIndex::new();
+        let mut slot = 0;
+        while slot < N {
+            index = index.insert(sprite_map[slot].0, slot);
+            slot += 1;
+        }
+        Self {
+            link,
+            sprite_map,
+            index,
+        }
     }
 
     pub fn link(&mut self) -> &mut L {
@@ -37,15 +49,45 @@ impl<L: i2c::Write, const ADDR: usize, const N: usize> FxDisplay<L, ADDR, N> {
     pub fn upload_sprite(&mut self, sprite: &FlashSprite) -> Result<(), <L as i2c::Write>::Error> {
         self.write(&[FxCommand::UploadSprite as _, sprite.id()])?;
 
-        self.write(&[
-            sprite.id(),
-            sprite.size().width,
-            sprite.size().height,
-            sprite.glyphs() as u8,
-        ])?;
+        let size = sprite.size();
+        let mut header = [0; 2 + 2 * COORD_LEN];
+        let (id, rest) = header.split_at_mut(1);
+        let (width, rest) = rest.split_at_mut(COORD_LEN);
+        let (height, glyphs) = rest.split_at_mut(COORD_LEN);
+        id[0] = sprite.id();
+        width.copy_from_slice(&size.width.to_le_bytes());
+        height.copy_from_slice(&size.height.to_le_bytes());
+        glyphs[0] = sprite.glyphs() as u8;
+        self.write(&header)?;
+
+        if !sprite.is_compressed() && sprite.depth() == BitDepth::Mono {
+            for chunk in sprite.raw().chunks(255) {
+                self.write(chunk)?;
+            }
+            return Ok(());
+        }
 
-        for chunk in sprite.raw().chunks(255) {
-            self.write(chunk)?;
+        let glyph_len = BitDepth::Mono.plane_len(size);
+        let mut bitmap = (0..sprite.glyphs()).flat_map(|idx| {
+            let raw = sprite
+                .glyph_bitmap(idx)
+                .map_or(&[][..], |glyph| &glyph[..glyph_len]);
+            let rle = sprite.glyph_rle(idx).into_iter().flatten();
+            raw.iter()
+                .copied()
+                .chain(rle)
+                .chain(core::iter::repeat(0))
+                .take(glyph_len)
+        });
+        let mut remaining = glyph_len * sprite.glyphs();
+        let mut chunk = [0; 255];
+        while remaining > 0 {
+            let len = remaining.min(chunk.len());
+            for byte in chunk[..len].iter_mut() {
+                *byte = bitmap.next().unwrap_or(0);
+            }
+            self.write(&chunk[..len])?;
+            remaining -= len;
         }
 
         Ok(())
@@ -58,7 +100,7 @@ impl<L: i2c::Write, const ADDR: usize, const N: usize> FxDisplay<L, ADDR, N> {
 
     pub fn delete_all_sprites(&mut self) -> Result<(), <L as i2c::Write>::Error> {
         self.write(&[FxCommand::DeleteAllSprites as _, 0])?;
-        self.write(&[b'd', b'e', b'l', b'a'])
+        self.write(b"dela")
     }
 
     fn write(&mut self, buf: &[u8]) -> Result<(), <L as i2c::Write>::Error> {
@@ -78,15 +120,23 @@ impl<L: i2c::WriteRead, const ADDR: usize, const N: usize> FxDisplay<L, ADDR, N>
     }
 }
 
-impl<L: i2c::Write, const ADDR: usize, const N: usize> Display for FxDisplay<L, ADDR, N> {
-    fn render(&mut self, req: RenderRequest) {
+impl<L: i2c::Write, const ADDR: usize, const N: usize> TryDisplay for FxDisplay<L, ADDR, N> {
+    type Error = <L as i2c::Write>::Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
         let req = self
-            .sprite_map
-            .iter()
-            .find(|(sprite_id, _)| req.sprite_id == *sprite_id)
-            .and_then(|(_, glyphs)| glyphs.index(req.glyph))
-            .map(|idx| RenderRequest::new(req.origin, req.sprite_id, idx as u8))
+            .index
+            .slot(req.sprite_id)
+            .and_then(|slot| self.sprite_map[slot].1.index(req.glyph))
+            .map(|idx| RenderRequest {
+                glyph: idx as u8,
+                ..req
+            })
             .unwrap_or(req);
-        self.link.write(ADDR as u8 | 1, &req.as_bytes()).ok();
+        if req.is_plain() {
+            return self.link.write(ADDR as u8 | 1, &req.as_bytes());
+        }
+        self.write(&[FxCommand::RenderExt as _, req.sprite_id])?;
+        self.write(&req.as_ext_bytes())
     }
 }
@@ -1,15 +1,17 @@
+use crate::drivers::draw_area;
 use crate::*;
-use display_interface::WriteOnlyDataCommand;
+use display_interface::{DisplayError, WriteOnlyDataCommand};
 use ist7920::*;
 
-impl<DI, MODE> Canvas for Ist7920<DI, MODE>
+impl<DI, MODE> TryCanvas for Ist7920<DI, MODE>
 where
     DI: WriteOnlyDataCommand,
 {
-    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
-        let start = bounds.start().into();
-        let end = bounds.end().into();
-        self.set_draw_area(start, end).ok();
-        self.draw(bitmap).ok();
+    type Error = DisplayError;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let (start, end) = draw_area(bounds).ok_or(DisplayError::OutOfBoundsError)?;
+        self.set_draw_area(start, end)?;
+        self.draw(bitmap)
     }
 }
@@ -1,5 +1,22 @@
 pub mod spi;
 
+#[cfg(any(
+    feature = "ist7920",
+    feature = "sh1106",
+    feature = "ssd1306",
+    feature = "ssd1309"
+))]
+#[allow(clippy::unnecessary_fallible_conversions)]
+pub(crate) fn draw_area(bounds: crate::Rectangle) -> Option<((u8, u8), (u8, u8))> {
+    if bounds.origin.y & 7 != 0 || bounds.size.height & 7 != 0 {
+        return None;
+    }
+    Some((
+        bounds.start().try_into().ok()?,
+        bounds.end().try_into().ok()?,
+    ))
+}
+
 #[cfg(feature = "fx")]
 pub mod fx;
 #[cfg(feature = "ist7920")]
@@ -12,3 +29,5 @@ pub mod ssd1306;
 pub mod ssd1309;
 #[cfg(feature = "st7567")]
 pub mod st7567;
+#[cfg(feature = "tft")]
+pub mod tft;
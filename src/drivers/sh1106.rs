@@ -1,15 +1,30 @@
+use crate::drivers::draw_area;
 use crate::*;
 use sh1106::interface::DisplayInterface;
 use sh1106::properties::DisplayProperties;
 
-impl<DI> Canvas for DisplayProperties<DI>
+#[derive(Debug)]
+pub enum Error<E> {
+    OutOfBounds,
+    Interface(E),
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(err: E) -> Self {
+        Error::Interface(err)
+    }
+}
+
+impl<DI> TryCanvas for DisplayProperties<DI>
 where
     DI: DisplayInterface,
 {
-    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
-        let start = bounds.start().into();
-        let end = bounds.end().into();
-        self.set_draw_area(start, end).ok();
-        self.draw(bitmap).ok();
+    type Error = Error<DI::Error>;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let (start, end) = draw_area(bounds).ok_or(Error::OutOfBounds)?;
+        self.set_draw_area(start, end)?;
+        self.draw(bitmap)?;
+        Ok(())
     }
 }
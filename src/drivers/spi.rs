@@ -32,6 +32,7 @@ impl OutputPin for NoDC {
 }
 
 pub enum Error<SPI: spi::Write<u8>> {
+    InvalidArgument,
     PinError,
     WriteError(<SPI as spi::Write<u8>>::Error),
 }
@@ -39,6 +40,7 @@ pub enum Error<SPI: spi::Write<u8>> {
 impl<SPI: spi::Write<u8>> core::fmt::Debug for Error<SPI> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            Self::InvalidArgument => write!(f, "Invalid Argument"),
             Self::PinError => write!(f, "GPIO Error"),
             Self::WriteError(_) => write!(f, "SPI Write Error"),
         }
@@ -1,15 +1,18 @@
+use crate::drivers::draw_area;
 use crate::*;
+use display_interface::DisplayError;
 use ssd1306::prelude::*;
 
-impl<DI, SIZE, MODE> Canvas for ssd1306::Ssd1306<DI, SIZE, MODE>
+impl<DI, SIZE, MODE> TryCanvas for ssd1306::Ssd1306<DI, SIZE, MODE>
 where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
 {
-    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
-        let start = bounds.start().into();
-        let end = bounds.end().into();
-        self.set_draw_area(start, end).ok();
-        self.draw(bitmap).ok();
+    type Error = DisplayError;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let (start, end) = draw_area(bounds).ok_or(DisplayError::OutOfBoundsError)?;
+        self.set_draw_area(start, end)?;
+        self.draw(bitmap)
     }
 }
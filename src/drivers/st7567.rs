@@ -1,4 +1,4 @@
-use crate::drivers::spi::SpiLink;
+use crate::drivers::spi::{Error, SpiLink};
 use crate::*;
 use embedded_hal::blocking::delay::DelayMs;
 use embedded_hal::blocking::spi;
@@ -30,6 +30,53 @@ pub enum Command {
     SetStartLine = 0x40,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bias {
+    OneNinth,
+    OneSeventh,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub bias: Bias,
+    pub regulator_ratio: u8,
+    pub contrast: u8,
+    pub flip_segments: bool,
+    pub flip_com: bool,
+    pub inverse: bool,
+    pub start_line: u8,
+}
+
+impl Config {
+    pub const fn new() -> Self {
+        Self {
+            bias: Bias::OneNinth,
+            regulator_ratio: 0x04,
+            contrast: 0x20,
+            flip_segments: false,
+            flip_com: false,
+            inverse: false,
+            start_line: 0,
+        }
+    }
+
+    pub const fn is_valid(&self) -> bool {
+        self.regulator_ratio <= MAX_REGULATOR_RATIO
+            && self.contrast <= MAX_CONTRAST
+            && self.start_line <= MAX_START_LINE
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub const MAX_REGULATOR_RATIO: u8 = 0x07;
+pub const MAX_CONTRAST: u8 = 0x3f;
+pub const MAX_START_LINE: u8 = 0x3f;
+
 pub struct ST7567<SPI, RST, CS, DC>
 where
     SPI: spi::Write<u8>,
@@ -40,6 +87,7 @@ where
     link: SpiLink<SPI, CS, DC>,
     rst: RST,
     offset: Point,
+    config: Config,
 }
 
 impl<SPI, RST, CS, DC> ST7567<SPI, RST, CS, DC>
@@ -54,6 +102,7 @@ where
             rst,
             link: SpiLink::new(spi, cs, dc),
             offset: Point::zero(),
+            config: Config::new(),
         }
     }
 
@@ -70,73 +119,188 @@ where
         self.offset = offset
     }
 
-    pub fn reset<D: DelayMs<u32>>(&mut self, delay: &mut D) {
-        self.rst.set_low().ok();
+    pub fn reset<D: DelayMs<u32>>(&mut self, delay: &mut D) -> Result<(), Error<SPI>> {
+        self.rst.set_low().map_err(|_| Error::PinError)?;
         delay.delay_ms(16_u32);
-        self.rst.set_high().ok();
+        self.rst.set_high().map_err(|_| Error::PinError)?;
         delay.delay_ms(64_u32);
 
+        self.configure(self.config)?;
+        self.power_on()
+    }
+
+    pub fn config(&self) -> Config {
+        self.config
+    }
+
+    pub fn configure(&mut self, config: Config) -> Result<(), Error<SPI>> {
+        if !config.is_valid() {
+            return Err(Error::InvalidArgument);
+        }
+        self.set_bias(config.bias)?;
+        self.set_regulator_ratio(config.regulator_ratio)?;
+        self.set_contrast(config.contrast)?;
+        self.set_segment_flip(config.flip_segments)?;
+        self.set_com_flip(config.flip_com)?;
+        self.set_inverse(config.inverse)?;
+        self.set_start_line(config.start_line)
+    }
+
+    pub fn set_bias(&mut self, bias: Bias) -> Result<(), Error<SPI>> {
+        let cmd = match bias {
+            Bias::OneNinth => Command::Bias1_9,
+            Bias::OneSeventh => Command::Bias1_7,
+        };
+        self.link.command(|tx| tx.write(&[cmd as _]))?;
+        self.config.bias = bias;
+        Ok(())
+    }
+
+    pub fn set_regulator_ratio(&mut self, ratio: u8) -> Result<(), Error<SPI>> {
+        if ratio > MAX_REGULATOR_RATIO {
+            return Err(Error::InvalidArgument);
+        }
         self.link
-            .command(|tx| {
-                tx.write(&[
-                    Command::SegmentDirectionNormal as _,
-                    Command::SetCOMNormal as _,
-                    Command::DisplayNormal as _,
-                    Command::SetStartLine as _,
-                    Command::PowerOn as _,
-                ])
-            })
-            .ok();
+            .command(|tx| tx.write(&[Command::RegulatorRatio as u8 | ratio]))?;
+        self.config.regulator_ratio = ratio;
+        Ok(())
+    }
+
+    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), Error<SPI>> {
+        if contrast > MAX_CONTRAST {
+            return Err(Error::InvalidArgument);
+        }
+        self.link
+            .command(|tx| tx.write(&[Command::Contrast as _, contrast]))?;
+        self.config.contrast = contrast;
+        Ok(())
+    }
+
+    pub fn set_segment_flip(&mut self, flip: bool) -> Result<(), Error<SPI>> {
+        let cmd = if flip {
+            Command::SegmentDirectionRev
+        } else {
+            Command::SegmentDirectionNormal
+        };
+        self.link.command(|tx| tx.write(&[cmd as _]))?;
+        self.config.flip_segments = flip;
+        Ok(())
+    }
+
+    pub fn set_com_flip(&mut self, flip: bool) -> Result<(), Error<SPI>> {
+        let cmd = if flip {
+            Command::SetCOMReverse
+        } else {
+            Command::SetCOMNormal
+        };
+        self.link.command(|tx| tx.write(&[cmd as _]))?;
+        self.config.flip_com = flip;
+        Ok(())
+    }
+
+    pub fn set_inverse(&mut self, inverse: bool) -> Result<(), Error<SPI>> {
+        let cmd = if inverse {
+            Command::DisplayInverse
+        } else {
+            Command::DisplayNormal
+        };
+        self.link.command(|tx| tx.write(&[cmd as _]))?;
+        self.config.inverse = inverse;
+        Ok(())
     }
 
-    pub fn on(&mut self) {
+    pub fn set_start_line(&mut self, line: u8) -> Result<(), Error<SPI>> {
+        if line > MAX_START_LINE {
+            return Err(Error::InvalidArgument);
+        }
         self.link
-            .command(|tx| tx.write(&[Command::DisplayOn as _]))
-            .ok();
+            .command(|tx| tx.write(&[Command::SetStartLine as u8 | line]))?;
+        self.config.start_line = line;
+        Ok(())
+    }
+
+    pub fn set_all_pixels_on(&mut self, on: bool) -> Result<(), Error<SPI>> {
+        let cmd = if on {
+            Command::AllPixelsOn
+        } else {
+            Command::DisplayRAM
+        };
+        self.link.command(|tx| tx.write(&[cmd as _]))
+    }
+
+    pub fn power_on(&mut self) -> Result<(), Error<SPI>> {
+        self.link.command(|tx| tx.write(&[Command::PowerOn as _]))
+    }
+
+    pub fn power_off(&mut self) -> Result<(), Error<SPI>> {
+        self.link.command(|tx| tx.write(&[Command::PowerOff as _]))
     }
 
-    pub fn off(&mut self) {
+    pub fn sleep(&mut self) -> Result<(), Error<SPI>> {
+        self.off()?;
+        self.set_all_pixels_on(true)
+    }
+
+    pub fn wake(&mut self) -> Result<(), Error<SPI>> {
+        self.set_all_pixels_on(false)?;
+        self.on()
+    }
+
+    pub fn on(&mut self) -> Result<(), Error<SPI>> {
+        self.link.command(|tx| tx.write(&[Command::DisplayOn as _]))
+    }
+
+    pub fn off(&mut self) -> Result<(), Error<SPI>> {
         self.link
             .command(|tx| tx.write(&[Command::DisplayOff as _]))
-            .ok();
     }
 }
 
-impl<SPI, RST, CS, DC> Canvas for ST7567<SPI, RST, CS, DC>
+impl<SPI, RST, CS, DC> TryCanvas for ST7567<SPI, RST, CS, DC>
 where
     SPI: spi::Write<u8>,
     RST: OutputPin,
     CS: OutputPin,
     DC: OutputPin,
 {
-    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
-        let col = bounds.origin.x + self.offset.x;
-        let page = (bounds.origin.y + self.offset.y) as u32 >> 3;
-        let chunks = bounds.size.height as u32 >> 3;
+    type Error = Error<SPI>;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        let col = bounds.origin.x as u32 + self.offset.x as u32;
+        let y = bounds.origin.y as u32 + self.offset.y as u32;
         let width = bounds.size.width as usize;
+        let height = bounds.size.height as usize;
+        if y & 7 != 0 || height & 7 != 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let pages = height >> 3;
+        if width == 0 || bitmap.len() < width * pages {
+            return Ok(());
+        }
 
         self.link
-            .command(|tx| tx.write(&[Command::EnterRWRMode as _]))
-            .ok();
+            .command(|tx| tx.write(&[Command::EnterRWRMode as _]))?;
 
-        for chunk in 0..chunks {
-            self.link
-                .command(|tx| {
-                    tx.write(&[
-                        Command::SetPage as u8 | (page + chunk) as u8,
-                        Command::SetColumnLow as u8 | (col & 0x0f),
-                        Command::SetColumnHigh as u8 | (col >> 4),
-                    ])
-                })
-                .ok();
-            let offset = width * chunk as usize;
+        for page in 0..pages {
+            self.link.command(|tx| {
+                tx.write(&[
+                    Command::SetPage as u8 | ((y >> 3) + page as u32) as u8,
+                    Command::SetColumnLow as u8 | (col & 0x0f) as u8,
+                    Command::SetColumnHigh as u8 | (col >> 4) as u8,
+                ])
+            })?;
+            let offset = width * page;
             self.link
-                .data(|tx| tx.write(&bitmap[offset..(offset + width)]))
-                .ok();
+                .data(|tx| tx.write(&bitmap[offset..(offset + width)]))?;
         }
 
         self.link
             .command(|tx| tx.write(&[Command::ExitRWRMode as _]))
-            .ok();
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let lines = MAX_START_LINE as u32 + 1;
+        self.set_start_line((start_line as u32 % lines) as u8)?;
+        Ok(Some(lines as Coord))
     }
 }
@@ -0,0 +1,294 @@
+use crate::drivers::spi::{Error, SpiLink};
+use crate::*;
+use embedded_hal::blocking::delay::DelayMs;
+use embedded_hal::blocking::spi;
+use embedded_hal::digital::v2::*;
+
+pub enum Command {
+    ColumnAddress = 0x2a,
+    DisplayInversionOff = 0x20,
+    DisplayInversionOn = 0x21,
+    DisplayOff = 0x28,
+    DisplayOn = 0x29,
+    MemoryAccessControl = 0x36,
+    MemoryWrite = 0x2c,
+    NormalMode = 0x13,
+    PixelFormat = 0x3a,
+    RowAddress = 0x2b,
+    SleepIn = 0x10,
+    SleepOut = 0x11,
+    SoftwareReset = 0x01,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TftModel {
+    ST7735,
+    ST7789,
+    ILI9341,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgb565(pub u16);
+
+impl Rgb565 {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(0xff, 0xff, 0xff);
+    pub const RED: Self = Self::new(0xff, 0, 0);
+    pub const GREEN: Self = Self::new(0, 0xff, 0);
+    pub const BLUE: Self = Self::new(0, 0, 0xff);
+    pub const YELLOW: Self = Self::new(0xff, 0xff, 0);
+    pub const CYAN: Self = Self::new(0, 0xff, 0xff);
+    pub const MAGENTA: Self = Self::new(0xff, 0, 0xff);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self(((r as u16 & 0xf8) << 8) | ((g as u16 & 0xfc) << 3) | (b as u16 >> 3))
+    }
+
+    pub fn blend(self, other: Self, level: GrayLevel) -> Self {
+        let level = level.min(MAX_GRAY_LEVEL) as u32;
+        let max = MAX_GRAY_LEVEL as u32;
+        let mix = |shift: u32, mask: u32| {
+            let a = (self.0 as u32 >> shift) & mask;
+            let b = (other.0 as u32 >> shift) & mask;
+            ((a * (max - level) + b * level) / max) << shift
+        };
+        Self((mix(11, 0x1f) | mix(5, 0x3f) | mix(0, 0x1f)) as u16)
+    }
+
+    pub const fn to_be_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub fg: Rgb565,
+    pub bg: Rgb565,
+}
+
+impl Palette {
+    pub const fn new(fg: Rgb565, bg: Rgb565) -> Self {
+        Self { fg, bg }
+    }
+
+    pub fn color(&self, level: GrayLevel) -> Rgb565 {
+        self.bg.blend(self.fg, level)
+    }
+}
+
+pub const PALETTES: usize = 16;
+
+pub struct Tft<SPI, RST, CS, DC>
+where
+    SPI: spi::Write<u8>,
+    RST: OutputPin,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    link: SpiLink<SPI, CS, DC>,
+    rst: RST,
+    model: TftModel,
+    offset: Point,
+    madctl: u8,
+    palettes: [Palette; PALETTES],
+}
+
+impl<SPI, RST, CS, DC> Tft<SPI, RST, CS, DC>
+where
+    SPI: spi::Write<u8>,
+    RST: OutputPin,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    pub fn new(model: TftModel, spi: SPI, cs: CS, dc: DC, rst: RST) -> Self {
+        Self {
+            rst,
+            model,
+            palettes: [Palette::new(Rgb565::WHITE, Rgb565::BLACK); PALETTES],
+            madctl: 0,
+            link: SpiLink::new(spi, cs, dc),
+            offset: Point::zero(),
+        }
+    }
+
+    pub fn release(self) -> (SPI, CS, DC, RST) {
+        let (spi, cs, dc) = self.link.release();
+        (spi, cs, dc, self.rst)
+    }
+
+    pub fn link(&mut self) -> &mut SpiLink<SPI, CS, DC> {
+        &mut self.link
+    }
+
+    pub fn model(&self) -> TftModel {
+        self.model
+    }
+
+    pub fn set_offset(&mut self, offset: Point) {
+        self.offset = offset
+    }
+
+    pub fn palette(&self, palette_id: PaletteId) -> Palette {
+        self.palettes[palette_id as usize % PALETTES]
+    }
+
+    pub fn set_palette(&mut self, palette_id: PaletteId, palette: Palette) {
+        self.palettes[palette_id as usize % PALETTES] = palette;
+    }
+
+    pub fn set_palettes(&mut self, palette: Palette) {
+        self.palettes = [palette; PALETTES];
+    }
+
+    pub fn reset<D: DelayMs<u32>>(&mut self, delay: &mut D) -> Result<(), Error<SPI>> {
+        self.rst.set_low().map_err(|_| Error::PinError)?;
+        delay.delay_ms(16_u32);
+        self.rst.set_high().map_err(|_| Error::PinError)?;
+        delay.delay_ms(120_u32);
+
+        self.link
+            .command(|tx| tx.write(&[Command::SoftwareReset as _]))?;
+        delay.delay_ms(150_u32);
+        self.link
+            .command(|tx| tx.write(&[Command::SleepOut as _]))?;
+        delay.delay_ms(120_u32);
+
+        let format = match self.model {
+            TftModel::ST7735 => 0x05,
+            TftModel::ST7789 | TftModel::ILI9341 => 0x55,
+        };
+        self.write_command(Command::PixelFormat, &[format])?;
+        self.set_memory_access(self.madctl)?;
+        let inversion = match self.model {
+            TftModel::ST7789 => Command::DisplayInversionOn,
+            TftModel::ST7735 | TftModel::ILI9341 => Command::DisplayInversionOff,
+        };
+        self.write_command(inversion, &[])?;
+        self.write_command(Command::NormalMode, &[])?;
+        delay.delay_ms(10_u32);
+        self.on()
+    }
+
+    pub fn set_memory_access(&mut self, madctl: u8) -> Result<(), Error<SPI>> {
+        self.madctl = madctl;
+        self.write_command(Command::MemoryAccessControl, &[madctl])
+    }
+
+    pub fn on(&mut self) -> Result<(), Error<SPI>> {
+        self.write_command(Command::DisplayOn, &[])
+    }
+
+    pub fn off(&mut self) -> Result<(), Error<SPI>> {
+        self.write_command(Command::DisplayOff, &[])
+    }
+
+    pub fn sleep(&mut self) -> Result<(), Error<SPI>> {
+        self.write_command(Command::SleepIn, &[])
+    }
+
+    pub fn fill(&mut self, area: Rectangle, color: Rgb565) -> Result<(), Error<SPI>> {
+        if area.is_empty() {
+            return Ok(());
+        }
+        self.set_window(area)?;
+        let pixels = area.size.width as usize * area.size.height as usize;
+        let color = color.to_be_bytes();
+        self.link.data(|tx| {
+            let mut chunk = [0; CHUNK_LEN];
+            for pixel in chunk.chunks_exact_mut(2) {
+                pixel.copy_from_slice(&color);
+            }
+            let mut remaining = pixels * 2;
+            while remaining > 0 {
+                let len = remaining.min(CHUNK_LEN);
+                tx.write(&chunk[..len])?;
+                remaining -= len;
+            }
+            Ok(())
+        })
+    }
+
+    fn set_window(&mut self, area: Rectangle) -> Result<(), Error<SPI>> {
+        let x0 = area.origin.x as u32 + self.offset.x as u32;
+        let y0 = area.origin.y as u32 + self.offset.y as u32;
+        let x1 = x0 + area.size.width as u32 - 1;
+        let y1 = y0 + area.size.height as u32 - 1;
+        let [x0h, x0l] = (x0 as u16).to_be_bytes();
+        let [x1h, x1l] = (x1 as u16).to_be_bytes();
+        let [y0h, y0l] = (y0 as u16).to_be_bytes();
+        let [y1h, y1l] = (y1 as u16).to_be_bytes();
+        self.write_command(Command::ColumnAddress, &[x0h, x0l, x1h, x1l])?;
+        self.write_command(Command::RowAddress, &[y0h, y0l, y1h, y1l])?;
+        self.link
+            .command(|tx| tx.write(&[Command::MemoryWrite as _]))
+    }
+
+    fn write_command(&mut self, cmd: Command, params: &[u8]) -> Result<(), Error<SPI>> {
+        self.link.command(|tx| tx.write(&[cmd as _]))?;
+        if params.is_empty() {
+            return Ok(());
+        }
+        self.link.data(|tx| tx.write(params))
+    }
+}
+
+const CHUNK_LEN: usize = 64;
+
+impl<SPI, RST, CS, DC> TryCanvas for Tft<SPI, RST, CS, DC>
+where
+    SPI: spi::Write<u8>,
+    RST: OutputPin,
+    CS: OutputPin,
+    DC: OutputPin,
+{
+    type Error = Error<SPI>;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.try_draw_gray(bounds, bitmap, BitDepth::Mono, Style::default())
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        if bounds.is_empty() {
+            return Ok(());
+        }
+        self.set_window(bounds)?;
+        let palette = self.palette(style.palette);
+        let level = style.level.min(MAX_GRAY_LEVEL);
+        let (fg, bg) = (palette.color(level).to_be_bytes(), palette.bg.to_be_bytes());
+        let size = bounds.size;
+        self.link.data(|tx| {
+            let mut chunk = [0; CHUNK_LEN];
+            let mut len = 0;
+            for y in 0..size.height as usize {
+                for x in 0..size.width as usize {
+                    let color = match depth {
+                        BitDepth::Mono => match depth.level(bitmap, size, x, y) {
+                            0 => bg,
+                            _ => fg,
+                        },
+                        _ => {
+                            let value = depth.level(bitmap, size, x, y) * level / MAX_GRAY_LEVEL;
+                            palette.color(value).to_be_bytes()
+                        }
+                    };
+                    chunk[len..(len + 2)].copy_from_slice(&color);
+                    len += 2;
+                    if len == CHUNK_LEN {
+                        tx.write(&chunk)?;
+                        len = 0;
+                    }
+                }
+            }
+            if len > 0 {
+                tx.write(&chunk[..len])?;
+            }
+            Ok(())
+        })
+    }
+}
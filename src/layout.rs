@@ -15,16 +15,16 @@ pub struct DirDown;
 pub struct DirUp;
 
 struct WrapInfo {
-    idx: u8,
-    wraps: u8,
-    width: u8,
-    height: u8,
+    idx: Coord,
+    wraps: Coord,
+    width: Coord,
+    height: Coord,
 }
 
 impl<DIR, const WRAP: usize> WrapLayout<DIR, WRAP> {
     fn wrap(node_idx: usize, sprite_size: Size) -> WrapInfo {
-        let idx = (node_idx % WRAP) as u8;
-        let wraps = (node_idx / WRAP) as u8;
+        let idx = (node_idx % WRAP) as Coord;
+        let wraps = (node_idx / WRAP) as Coord;
         let width = sprite_size.width;
         let height = sprite_size.height;
         WrapInfo {
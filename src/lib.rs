@@ -4,23 +4,143 @@
 pub mod drivers;
 pub mod layout;
 
+mod canvas;
 mod display;
 mod sprites;
+mod store;
 mod widgets;
 
+pub use canvas::*;
 pub use display::*;
 pub use sprites::*;
+pub use store::*;
 pub use widgets::*;
 
+#[cfg(feature = "macros")]
+pub use klaptik_macros::*;
+
 pub type Glyph = u8;
 pub type SpriteId = u8;
+pub type GrayLevel = u8;
+
+pub const MAX_GRAY_LEVEL: GrayLevel = 15;
+
+pub type PaletteId = u8;
+
+#[cfg(not(feature = "wide"))]
+pub type Coord = u8;
+#[cfg(feature = "wide")]
+pub type Coord = u16;
+
+const COORD_LEN: usize = core::mem::size_of::<Coord>();
 
 pub trait Canvas {
     fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]);
 }
 
+pub trait TryCanvas {
+    type Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error>;
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        let _ = style;
+        let plane = depth.plane_len(bounds.size);
+        self.try_draw(bounds, bitmap.get(..plane).unwrap_or(bitmap))
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let _ = start_line;
+        Ok(None)
+    }
+}
+
+impl<C: TryCanvas + ?Sized> TryCanvas for &mut C {
+    type Error = C::Error;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        (**self).try_draw(bounds, bitmap)
+    }
+
+    fn try_draw_gray(
+        &mut self,
+        bounds: Rectangle,
+        bitmap: &[u8],
+        depth: BitDepth,
+        style: Style,
+    ) -> Result<(), Self::Error> {
+        (**self).try_draw_gray(bounds, bitmap, depth, style)
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        (**self).try_scroll(start_line)
+    }
+}
+
+impl<T: TryCanvas> Canvas for T {
+    fn draw(&mut self, bounds: Rectangle, bitmap: &[u8]) {
+        self.try_draw(bounds, bitmap).ok();
+    }
+}
+
+pub struct Fallible<T>(pub T);
+
+impl<C: Canvas> TryCanvas for Fallible<C> {
+    type Error = core::convert::Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.0.draw(bounds, bitmap);
+        Ok(())
+    }
+}
+
+impl<D: Display> TryDisplay for Fallible<D> {
+    type Error = core::convert::Infallible;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        self.0.render(req);
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        Ok(self.0.scroll(start_line))
+    }
+}
+
 pub trait Display {
     fn render(&mut self, req: RenderRequest);
+
+    fn scroll(&mut self, start_line: Coord) -> Option<Coord> {
+        let _ = start_line;
+        None
+    }
+}
+
+pub trait TryDisplay {
+    type Error;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error>;
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        let _ = start_line;
+        Ok(None)
+    }
+}
+
+impl<T: TryDisplay + ?Sized> Display for T {
+    fn render(&mut self, req: RenderRequest) {
+        self.try_render(req).ok();
+    }
+
+    fn scroll(&mut self, start_line: Coord) -> Option<Coord> {
+        self.try_scroll(start_line).ok().flatten()
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,32 +149,171 @@ pub struct RenderRequest {
     pub origin: Point,
     pub sprite_id: SpriteId,
     pub glyph: Glyph,
+    pub level: GrayLevel,
+    pub palette: PaletteId,
+    pub attributes: Attributes,
 }
 
 impl RenderRequest {
-    pub fn new(origin: Point, sprite_id: SpriteId, glyph: Glyph) -> Self {
+    pub const fn new(origin: Point, sprite_id: SpriteId, glyph: Glyph) -> Self {
         Self {
             origin,
             sprite_id,
             glyph,
+            level: MAX_GRAY_LEVEL,
+            palette: 0,
+            attributes: Attributes::NONE,
+        }
+    }
+
+    pub const fn with_level(self, level: GrayLevel) -> Self {
+        Self {
+            level: if level > MAX_GRAY_LEVEL {
+                MAX_GRAY_LEVEL
+            } else {
+                level
+            },
+            ..self
         }
     }
 
+    pub const fn with_palette(self, palette: PaletteId) -> Self {
+        Self { palette, ..self }
+    }
+
+    pub const fn with_attributes(self, attributes: Attributes) -> Self {
+        Self { attributes, ..self }
+    }
+
+    pub const fn style(&self) -> Style {
+        Style::new(self.level, self.palette)
+    }
+
+    pub const LEN: usize = 2 * COORD_LEN + 2;
+    pub const EXT_LEN: usize = Self::LEN + 2;
+
+    pub const fn is_plain(&self) -> bool {
+        self.level == MAX_GRAY_LEVEL && self.palette == 0 && self.attributes.is_empty()
+    }
+
     pub fn from_bytes(bytes: &[u8]) -> Self {
-        assert!(bytes.len() == 4);
-        Self::new(Point::new(bytes[0], bytes[1]), bytes[2], bytes[3])
+        assert!(bytes.len() == Self::LEN || bytes.len() == Self::EXT_LEN);
+        let (x, rest) = bytes.split_at(COORD_LEN);
+        let (y, rest) = rest.split_at(COORD_LEN);
+        let x = Coord::from_le_bytes(x.try_into().unwrap());
+        let y = Coord::from_le_bytes(y.try_into().unwrap());
+        let req = Self::new(Point::new(x, y), rest[0], rest[1]);
+        match rest.get(2..4) {
+            Some(&[style, palette]) => req
+                .with_level(style & 0x0f)
+                .with_attributes(Attributes::from_bits(style >> 4))
+                .with_palette(palette),
+            _ => req,
+        }
     }
 
-    pub fn as_bytes(&self) -> [u8; 4] {
-        [self.origin.x, self.origin.y, self.sprite_id, self.glyph]
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        let (x, rest) = bytes.split_at_mut(COORD_LEN);
+        let (y, rest) = rest.split_at_mut(COORD_LEN);
+        x.copy_from_slice(&self.origin.x.to_le_bytes());
+        y.copy_from_slice(&self.origin.y.to_le_bytes());
+        rest[0] = self.sprite_id;
+        rest[1] = self.glyph;
+        bytes
+    }
+
+    pub fn as_ext_bytes(&self) -> [u8; Self::EXT_LEN] {
+        let mut bytes = [0; Self::EXT_LEN];
+        bytes[..Self::LEN].copy_from_slice(&self.as_bytes());
+        bytes[Self::LEN] = self.attributes.bits() << 4 | self.level;
+        bytes[Self::LEN + 1] = self.palette;
+        bytes
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub level: GrayLevel,
+    pub palette: PaletteId,
+}
+
+impl Style {
+    pub const fn new(level: GrayLevel, palette: PaletteId) -> Self {
+        Self { level, palette }
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::new(MAX_GRAY_LEVEL, 0)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Attributes(u8);
+
+impl Attributes {
+    pub const NONE: Self = Self(0);
+    pub const INVERT: Self = Self(1);
+    pub const FLIP_H: Self = Self(2);
+    pub const FLIP_V: Self = Self(4);
+    pub const SCALE_2X: Self = Self(8);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits & 0x0f)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub const fn scale(self) -> usize {
+        if self.contains(Self::SCALE_2X) {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl core::ops::BitOr for Attributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
     }
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Point {
-    pub x: u8,
-    pub y: u8,
+    pub x: Coord,
+    pub y: Coord,
 }
 
 impl Point {
@@ -62,26 +321,35 @@ impl Point {
         Self::new(0, 0)
     }
 
-    pub const fn new(x: u8, y: u8) -> Self {
+    pub const fn new(x: Coord, y: Coord) -> Self {
         Self { x, y }
     }
 }
 
-impl From<Point> for (u8, u8) {
+impl From<Point> for (Coord, Coord) {
     fn from(p: Point) -> Self {
         (p.x, p.y)
     }
 }
 
+#[cfg(feature = "wide")]
+impl TryFrom<Point> for (u8, u8) {
+    type Error = core::num::TryFromIntError;
+
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        Ok((p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Size {
-    pub width: u8,
-    pub height: u8,
+    pub width: Coord,
+    pub height: Coord,
 }
 
 impl Size {
-    pub const fn new(width: u8, height: u8) -> Self {
+    pub const fn new(width: Coord, height: Coord) -> Self {
         Self { width, height }
     }
 }
@@ -105,7 +373,48 @@ impl Rectangle {
     pub fn end(&self) -> Point {
         let origin = self.origin;
         let size = self.size;
-        Point::new(origin.x + size.width, origin.y + size.height)
+        Point::new(
+            origin.x.saturating_add(size.width),
+            origin.y.saturating_add(size.height),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size.width == 0 || self.size.height == 0
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let (x, y) = (point.x as u32, point.y as u32);
+        let (x0, y0) = (self.origin.x as u32, self.origin.y as u32);
+        x >= x0 && y >= y0 && x < x0 + self.size.width as u32 && y < y0 + self.size.height as u32
+    }
+
+    pub fn intersect(&self, other: &Rectangle) -> Option<Rectangle> {
+        let x0 = self.origin.x.max(other.origin.x) as u32;
+        let y0 = self.origin.y.max(other.origin.y) as u32;
+        let x1 = (self.origin.x as u32 + self.size.width as u32)
+            .min(other.origin.x as u32 + other.size.width as u32);
+        let y1 = (self.origin.y as u32 + self.size.height as u32)
+            .min(other.origin.y as u32 + other.size.height as u32);
+        if x1 <= x0 || y1 <= y0 {
+            return None;
+        }
+        Some(Rectangle::new(
+            Point::new(x0 as _, y0 as _),
+            Size::new((x1 - x0) as _, (y1 - y0) as _),
+        ))
+    }
+
+    pub fn clamp(&self, point: Point) -> Point {
+        let end = self.end();
+        Point::new(
+            point
+                .x
+                .clamp(self.origin.x, end.x.saturating_sub(1).max(self.origin.x)),
+            point
+                .y
+                .clamp(self.origin.y, end.y.saturating_sub(1).max(self.origin.y)),
+        )
     }
 }
 
@@ -113,6 +422,7 @@ pub enum Glyphs {
     Single,
     Sequential(u8),
     Alphabet(&'static [Glyph]),
+    Indexed(&'static GlyphIndex),
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -122,6 +432,7 @@ impl Glyphs {
             Glyphs::Single => Some(0),
             Glyphs::Sequential(len) if glyph < *len => Some(glyph as _),
             Glyphs::Alphabet(glyphs) => glyphs.iter().position(|g| *g == glyph),
+            Glyphs::Indexed(index) => index.index(glyph),
             _ => None,
         }
     }
@@ -131,6 +442,40 @@ impl Glyphs {
             Glyphs::Single => 1,
             Glyphs::Sequential(len) => *len as usize,
             Glyphs::Alphabet(glyphs) => glyphs.len(),
+            Glyphs::Indexed(index) => index.len(),
         }
     }
 }
+
+pub struct GlyphIndex {
+    len: usize,
+    table: [u8; 256],
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl GlyphIndex {
+    pub const fn new(alphabet: &[Glyph]) -> Self {
+        assert!(alphabet.len() < 256, "alphabet is too long");
+        let mut table = [u8::MAX; 256];
+        let mut idx = alphabet.len();
+        while idx > 0 {
+            idx -= 1;
+            table[alphabet[idx] as usize] = idx as u8;
+        }
+        Self {
+            len: alphabet.len(),
+            table,
+        }
+    }
+
+    pub fn index(&self, glyph: Glyph) -> Option<usize> {
+        match self.table[glyph as usize] {
+            u8::MAX => None,
+            idx => Some(idx as usize),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -6,43 +6,383 @@ pub struct FlashSprite {
     glyphs: usize,
     glyph_len: usize,
     bitmap: &'static [u8],
+    depth: BitDepth,
+    encoding: Encoding,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitDepth {
+    Mono = 1,
+    Gray2 = 2,
+    Gray4 = 4,
+}
+
+impl BitDepth {
+    pub const fn bits(self) -> usize {
+        self as usize
+    }
+
+    pub const fn max_level(self) -> u8 {
+        (1 << self.bits()) - 1
+    }
+
+    pub const fn plane_len(self, size: Size) -> usize {
+        size.width as usize * ((size.height as usize + 7) >> 3)
+    }
+
+    pub const fn bitmap_len(self, size: Size) -> usize {
+        self.plane_len(size) * self.bits()
+    }
+
+    pub fn level(self, bitmap: &[u8], size: Size, x: usize, y: usize) -> GrayLevel {
+        let plane = self.plane_len(size);
+        let offset = (y >> 3) * size.width as usize + x;
+        let value = (0..self.bits()).fold(0, |value, bit| {
+            let on = bitmap
+                .get(bit * plane + offset)
+                .is_some_and(|byte| byte & (1 << (y & 7)) != 0);
+            (value << 1) | on as u8
+        });
+        (value as u16 * MAX_GRAY_LEVEL as u16 / self.max_level() as u16) as GrayLevel
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Encoding {
+    Raw,
+    Rle(&'static [u16]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpriteError {
+    NoGlyphs,
+    EmptySize,
+    UnalignedHeight,
+    BitmapLength,
+    GlyphOffsets,
+}
+
+impl SpriteError {
+    pub(crate) const fn validate(
+        glyphs: usize,
+        size: Size,
+        bitmap_len: usize,
+    ) -> Result<usize, SpriteError> {
+        if glyphs == 0 {
+            return Err(SpriteError::NoGlyphs);
+        }
+        if size.width == 0 || size.height == 0 {
+            return Err(SpriteError::EmptySize);
+        }
+        if size.height & 7 != 0 {
+            return Err(SpriteError::UnalignedHeight);
+        }
+        let glyph_len = size.width as usize * (size.height as usize >> 3);
+        if bitmap_len != glyph_len * glyphs {
+            return Err(SpriteError::BitmapLength);
+        }
+        Ok(glyph_len)
+    }
+
+    pub(crate) const fn raise(self) -> ! {
+        match self {
+            SpriteError::NoGlyphs => panic!("sprite must have at least one glyph"),
+            SpriteError::EmptySize => panic!("sprite size must not be empty"),
+            SpriteError::UnalignedHeight => panic!("sprite height must be a multiple of 8"),
+            SpriteError::BitmapLength => panic!("bitmap length does not match sprite size"),
+            SpriteError::GlyphOffsets => panic!("glyph offsets do not match compressed bitmap"),
+        }
+    }
 }
 
 impl FlashSprite {
     pub const fn new(id: SpriteId, glyphs: usize, size: Size, bitmap: &'static [u8]) -> Self {
-        let glyph_len = bitmap.len() / glyphs;
-        Self {
+        match Self::try_new(id, glyphs, size, bitmap) {
+            Ok(sprite) => sprite,
+            Err(err) => err.raise(),
+        }
+    }
+
+    pub const fn try_new(
+        id: SpriteId,
+        glyphs: usize,
+        size: Size,
+        bitmap: &'static [u8],
+    ) -> Result<Self, SpriteError> {
+        let glyph_len = match SpriteError::validate(glyphs, size, bitmap.len()) {
+            Ok(glyph_len) => glyph_len,
+            Err(err) => return Err(err),
+        };
+        Ok(Self {
             id,
             glyphs,
             size,
             bitmap,
             glyph_len,
+            depth: BitDepth::Mono,
+            encoding: Encoding::Raw,
+        })
+    }
+
+    pub const fn gray(
+        id: SpriteId,
+        glyphs: usize,
+        size: Size,
+        depth: BitDepth,
+        bitmap: &'static [u8],
+    ) -> Self {
+        match Self::try_gray(id, glyphs, size, depth, bitmap) {
+            Ok(sprite) => sprite,
+            Err(err) => err.raise(),
         }
     }
+
+    pub const fn try_gray(
+        id: SpriteId,
+        glyphs: usize,
+        size: Size,
+        depth: BitDepth,
+        bitmap: &'static [u8],
+    ) -> Result<Self, SpriteError> {
+        let raw_len = glyphs * size.width as usize * (size.height as usize >> 3);
+        if let Err(err) = SpriteError::validate(glyphs, size, raw_len) {
+            return Err(err);
+        }
+        if bitmap.len() != raw_len * depth.bits() {
+            return Err(SpriteError::BitmapLength);
+        }
+        Ok(Self {
+            id,
+            glyphs,
+            size,
+            bitmap,
+            glyph_len: depth.bitmap_len(size),
+            depth,
+            encoding: Encoding::Raw,
+        })
+    }
+
+    pub const fn rle(
+        id: SpriteId,
+        glyphs: usize,
+        size: Size,
+        offsets: &'static [u16],
+        bitmap: &'static [u8],
+    ) -> Self {
+        match Self::try_rle(id, glyphs, size, offsets, bitmap) {
+            Ok(sprite) => sprite,
+            Err(err) => err.raise(),
+        }
+    }
+
+    pub const fn try_rle(
+        id: SpriteId,
+        glyphs: usize,
+        size: Size,
+        offsets: &'static [u16],
+        bitmap: &'static [u8],
+    ) -> Result<Self, SpriteError> {
+        let raw_len = glyphs * size.width as usize * (size.height as usize >> 3);
+        let glyph_len = match SpriteError::validate(glyphs, size, raw_len) {
+            Ok(glyph_len) => glyph_len,
+            Err(err) => return Err(err),
+        };
+        if offsets.len() != glyphs {
+            return Err(SpriteError::GlyphOffsets);
+        }
+        let mut idx = 0;
+        while idx < glyphs {
+            let offset = offsets[idx] as usize;
+            if offset > bitmap.len() || (idx > 0 && offset < offsets[idx - 1] as usize) {
+                return Err(SpriteError::GlyphOffsets);
+            }
+            idx += 1;
+        }
+        Ok(Self {
+            id,
+            glyphs,
+            size,
+            bitmap,
+            glyph_len,
+            depth: BitDepth::Mono,
+            encoding: Encoding::Rle(offsets),
+        })
+    }
 }
 
 impl FlashSprite {
-    pub fn id(&self) -> SpriteId {
+    pub const fn id(&self) -> SpriteId {
         self.id
     }
 
-    pub fn size(&self) -> Size {
+    pub const fn size(&self) -> Size {
         self.size
     }
 
-    pub fn glyphs(&self) -> usize {
+    pub const fn glyphs(&self) -> usize {
         self.glyphs
     }
 
-    pub fn raw(&self) -> &[u8] {
+    pub const fn depth(&self) -> BitDepth {
+        self.depth
+    }
+
+    pub const fn raw(&self) -> &[u8] {
         self.bitmap
     }
 
+    pub const fn is_compressed(&self) -> bool {
+        matches!(self.encoding, Encoding::Rle(_))
+    }
+
     pub fn glyph_bitmap(&self, glyph_index: usize) -> Option<&[u8]> {
-        if glyph_index >= self.glyphs {
+        if glyph_index >= self.glyphs || self.is_compressed() {
             return None;
         }
         let offset = glyph_index * self.glyph_len;
-        Some(&self.bitmap[offset..][..self.glyph_len])
+        self.bitmap.get(offset..(offset + self.glyph_len))
+    }
+
+    pub fn glyph_rle(&self, glyph_index: usize) -> Option<RleDecoder<'static>> {
+        let offsets = match self.encoding {
+            Encoding::Rle(offsets) => offsets,
+            Encoding::Raw => return None,
+        };
+        let start = *offsets.get(glyph_index)? as usize;
+        let end = offsets
+            .get(glyph_index + 1)
+            .map_or(self.bitmap.len(), |end| *end as usize);
+        Some(RleDecoder::new(&self.bitmap[start..end]))
+    }
+}
+
+pub struct RleDecoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    run: usize,
+    repeat: Option<u8>,
+}
+
+impl<'a> RleDecoder<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            run: 0,
+            repeat: None,
+        }
+    }
+
+    pub fn fill(&mut self, buf: &mut [u8]) {
+        for byte in buf.iter_mut() {
+            *byte = self.next().unwrap_or(0);
+        }
+    }
+
+    fn take(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+}
+
+impl Iterator for RleDecoder<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.run == 0 {
+            let ctrl = self.take()?;
+            if ctrl < 0x80 {
+                self.run = ctrl as usize + 1;
+                self.repeat = None;
+            } else {
+                self.run = (ctrl - 0x80) as usize + 2;
+                self.repeat = Some(self.take()?);
+            }
+        }
+        self.run -= 1;
+        match self.repeat {
+            Some(byte) => Some(byte),
+            None => self.take(),
+        }
     }
 }
+
+#[macro_export]
+macro_rules! sprite {
+    ($id:expr, $([$($row:literal),+ $(,)?]),+ $(,)?) => {{
+        const GLYPHS: &[&[&str]] = &[$(&[$($row),+]),+];
+        const LEN: usize = $crate::ascii_sprite_len(GLYPHS);
+        const BITMAP: [u8; LEN] = $crate::ascii_sprite_bitmap::<LEN>(GLYPHS);
+        $crate::FlashSprite::new($id, GLYPHS.len(), $crate::ascii_sprite_size(GLYPHS), &BITMAP)
+    }};
+}
+
+#[doc(hidden)]
+pub const fn ascii_sprite_size(glyphs: &[&[&str]]) -> Size {
+    assert!(!glyphs.is_empty(), "sprite must have at least one glyph");
+    let rows = glyphs[0];
+    assert!(!rows.is_empty(), "glyph must have at least one row");
+    let width = rows[0].len();
+    assert!(width > 0, "glyph must have at least one column");
+
+    let mut glyph = 0;
+    while glyph < glyphs.len() {
+        let rows = glyphs[glyph];
+        assert!(rows.len() == glyphs[0].len(), "glyph heights differ");
+        let mut row = 0;
+        while row < rows.len() {
+            let bytes = rows[row].as_bytes();
+            assert!(bytes.len() == width, "glyph row widths differ");
+            let mut col = 0;
+            while col < bytes.len() {
+                assert!(
+                    bytes[col] == b'#' || bytes[col] == b'.',
+                    "glyph rows may only contain '#' and '.'"
+                );
+                col += 1;
+            }
+            row += 1;
+        }
+        glyph += 1;
+    }
+
+    let height = (rows.len() + 7) & !7;
+    assert!(width <= Coord::MAX as usize && height <= Coord::MAX as usize);
+    Size::new(width as Coord, height as Coord)
+}
+
+#[doc(hidden)]
+pub const fn ascii_sprite_len(glyphs: &[&[&str]]) -> usize {
+    let size = ascii_sprite_size(glyphs);
+    glyphs.len() * size.width as usize * (size.height as usize >> 3)
+}
+
+#[doc(hidden)]
+pub const fn ascii_sprite_bitmap<const LEN: usize>(glyphs: &[&[&str]]) -> [u8; LEN] {
+    let size = ascii_sprite_size(glyphs);
+    let width = size.width as usize;
+    let glyph_len = width * (size.height as usize >> 3);
+    let mut bitmap = [0; LEN];
+
+    let mut glyph = 0;
+    while glyph < glyphs.len() {
+        let rows = glyphs[glyph];
+        let mut row = 0;
+        while row < rows.len() {
+            let bytes = rows[row].as_bytes();
+            let mut col = 0;
+            while col < width {
+                if bytes[col] == b'#' {
+                    bitmap[glyph * glyph_len + (row >> 3) * width + col] |= 1 << (row & 7);
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+        glyph += 1;
+    }
+
+    bitmap
+}
@@ -0,0 +1,90 @@
+use crate::store::draw_glyph;
+use crate::*;
+
+#[derive(Clone, Copy)]
+pub struct SpriteIndex {
+    slots: [u8; 256],
+}
+
+impl SpriteIndex {
+    pub const fn new() -> Self {
+        Self {
+            slots: [u8::MAX; 256],
+        }
+    }
+
+    pub const fn insert(mut self, sprite_id: SpriteId, slot: usize) -> Self {
+        assert!(slot < u8::MAX as usize, "too many sprites");
+        assert!(
+            self.slots[sprite_id as usize] == u8::MAX,
+            "duplicate sprite id"
+        );
+        self.slots[sprite_id as usize] = slot as u8;
+        self
+    }
+
+    pub fn slot(&self, sprite_id: SpriteId) -> Option<usize> {
+        match self.slots[sprite_id as usize] {
+            u8::MAX => None,
+            slot => Some(slot as usize),
+        }
+    }
+}
+
+impl Default for SpriteIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct SpriteMap<const N: usize> {
+    sprites: [(FlashSprite, Glyphs); N],
+    index: SpriteIndex,
+}
+
+impl<const N: usize> SpriteMap<N> {
+    pub const fn new(sprites: [(FlashSprite, Glyphs); N]) -> Self {
+        let mut index = SpriteIndex::new();
+        let mut slot = 0;
+        while slot < N {
+            index = index.insert(sprites[slot].0.id(), slot);
+            slot += 1;
+        }
+        Self { sprites, index }
+    }
+
+    pub fn get(&self, sprite_id: SpriteId) -> Option<&(FlashSprite, Glyphs)> {
+        self.index.slot(sprite_id).map(|slot| &self.sprites[slot])
+    }
+}
+
+impl<const N: usize> SpriteStore for SpriteMap<N> {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        (&self).sprite_size(sprite_id)
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        (&*self).draw(canvas, req)
+    }
+}
+
+impl<const N: usize> SpriteStore for &SpriteMap<N> {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        self.get(sprite_id).map(|(sprite, _)| sprite.size())
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        match self.get(req.sprite_id) {
+            Some((sprite, glyphs)) => draw_glyph(canvas, req, sprite, glyphs),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::*;
+
+mod map;
+#[cfg(feature = "storage")]
+mod pack;
+mod ram;
+
+pub use map::*;
+#[cfg(feature = "storage")]
+pub use pack::*;
+pub use ram::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageError {
+    NotAligned,
+    OutOfBounds,
+    Format,
+    Capacity,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderError<E> {
+    Canvas(E),
+    Storage(StorageError),
+}
+
+pub trait SpriteStore {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size>;
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>>;
+
+    fn take_invalidated(&mut self) -> Option<SpriteId> {
+        None
+    }
+}
+
+impl<S: SpriteStore + ?Sized> SpriteStore for &mut S {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        (**self).sprite_size(sprite_id)
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        (**self).draw(canvas, req)
+    }
+
+    fn take_invalidated(&mut self) -> Option<SpriteId> {
+        (**self).take_invalidated()
+    }
+}
+
+impl<const N: usize> SpriteStore for [(FlashSprite, Glyphs); N] {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        self.iter()
+            .find(|(sprite, _)| sprite.id() == sprite_id)
+            .map(|(sprite, _)| sprite.size())
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        match self.iter().find(|(sprite, _)| sprite.id() == req.sprite_id) {
+            Some((sprite, glyphs)) => draw_glyph(canvas, req, sprite, glyphs),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<A: SpriteStore, B: SpriteStore> SpriteStore for (A, B) {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        self.0
+            .sprite_size(sprite_id)
+            .or_else(|| self.1.sprite_size(sprite_id))
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        if self.0.sprite_size(req.sprite_id).is_some() {
+            self.0.draw(canvas, req)
+        } else {
+            self.1.draw(canvas, req)
+        }
+    }
+
+    fn take_invalidated(&mut self) -> Option<SpriteId> {
+        self.0
+            .take_invalidated()
+            .or_else(|| self.1.take_invalidated())
+    }
+}
+
+impl<A: SpriteStore, B: SpriteStore, S: SpriteStore> SpriteStore for (A, B, S) {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        self.0
+            .sprite_size(sprite_id)
+            .or_else(|| self.1.sprite_size(sprite_id))
+            .or_else(|| self.2.sprite_size(sprite_id))
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        if self.0.sprite_size(req.sprite_id).is_some() {
+            self.0.draw(canvas, req)
+        } else if self.1.sprite_size(req.sprite_id).is_some() {
+            self.1.draw(canvas, req)
+        } else {
+            self.2.draw(canvas, req)
+        }
+    }
+
+    fn take_invalidated(&mut self) -> Option<SpriteId> {
+        self.0
+            .take_invalidated()
+            .or_else(|| self.1.take_invalidated())
+            .or_else(|| self.2.take_invalidated())
+    }
+}
+
+pub(crate) fn draw_glyph<C: TryCanvas>(
+    canvas: &mut C,
+    req: RenderRequest,
+    sprite: &FlashSprite,
+    glyphs: &Glyphs,
+) -> Result<(), RenderError<C::Error>> {
+    let idx = match glyphs.index(req.glyph) {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+    let bounds = Rectangle::new(req.origin, sprite.size());
+    if let Some(bitmap) = sprite.glyph_bitmap(idx) {
+        return canvas
+            .try_draw_gray(bounds, bitmap, sprite.depth(), req.style())
+            .map_err(RenderError::Canvas);
+    }
+    match sprite.glyph_rle(idx) {
+        Some(mut decoder) => {
+            let mut scratch = [0; RLE_SCRATCH];
+            draw_streamed(canvas, bounds, req.style(), &mut scratch, |_, buf| {
+                decoder.fill(buf);
+                Ok(())
+            })
+        }
+        None => Ok(()),
+    }
+}
+
+const RLE_SCRATCH: usize = 128;
+
+pub(crate) fn draw_streamed<C: TryCanvas>(
+    canvas: &mut C,
+    bounds: Rectangle,
+    style: Style,
+    scratch: &mut [u8],
+    mut read: impl FnMut(usize, &mut [u8]) -> Result<(), StorageError>,
+) -> Result<(), RenderError<C::Error>> {
+    if scratch.is_empty() {
+        return Err(RenderError::Storage(StorageError::Capacity));
+    }
+    if bounds.is_empty() {
+        return Ok(());
+    }
+
+    let width = bounds.size.width as usize;
+    let pages = bounds.size.height as usize >> 3;
+    let chunk_width = width.min(scratch.len());
+    let chunk_pages = (scratch.len() / width).clamp(1, pages);
+    let mut page = 0;
+    while page < pages {
+        let rows = chunk_pages.min(pages - page);
+        let mut col = 0;
+        while col < width {
+            let cols = chunk_width.min(width - col);
+            let len = if cols == width { rows * width } else { cols };
+            let data = &mut scratch[..len];
+            read(page * width + col, data).map_err(RenderError::Storage)?;
+            let origin = Point::new(
+                bounds.origin.x + col as Coord,
+                bounds.origin.y + (page << 3) as Coord,
+            );
+            let size = Size::new(cols as Coord, (rows << 3) as Coord);
+            canvas
+                .try_draw_gray(Rectangle::new(origin, size), data, BitDepth::Mono, style)
+                .map_err(RenderError::Canvas)?;
+            col += cols;
+        }
+        page += if chunk_width == width { rows } else { 1 };
+    }
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use crate::*;
+use embedded_storage::nor_flash::{NorFlashError, NorFlashErrorKind, ReadNorFlash};
+
+pub const PACK_MAGIC: [u8; 4] = *b"KLPK";
+pub const PACK_VERSION: u8 = 1;
+pub const PACK_HEADER_LEN: usize = 8;
+pub const PACK_ENTRY_LEN: usize = 16;
+
+const READ_WINDOW: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackGlyphs {
+    Single,
+    Sequential(u16),
+    Alphabet(u16, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackSprite {
+    pub id: SpriteId,
+    pub size: Size,
+    pub glyphs: PackGlyphs,
+    pub bitmap: u32,
+}
+
+impl PackSprite {
+    fn parse(raw: &[u8; PACK_ENTRY_LEN]) -> Result<Self, StorageError> {
+        let u16_at = |offset: usize| u16::from_le_bytes([raw[offset], raw[offset + 1]]);
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes([
+                raw[offset],
+                raw[offset + 1],
+                raw[offset + 2],
+                raw[offset + 3],
+            ])
+        };
+
+        let glyphs = match (raw[1], u16_at(2)) {
+            (_, 0) => return Err(StorageError::Format),
+            (0, 1) => PackGlyphs::Single,
+            (1, len) => PackGlyphs::Sequential(len),
+            (2, len) => PackGlyphs::Alphabet(len, u32_at(12)),
+            _ => return Err(StorageError::Format),
+        };
+        let (width, height) = (u16_at(4) as usize, u16_at(6) as usize);
+        if width == 0 || height == 0 || height & 7 != 0 || width.max(height) > Coord::MAX as usize {
+            return Err(StorageError::Format);
+        }
+
+        Ok(Self {
+            id: raw[0],
+            size: Size::new(width as Coord, height as Coord),
+            glyphs,
+            bitmap: u32_at(8),
+        })
+    }
+
+    pub fn glyph_len(&self) -> usize {
+        self.size.width as usize * (self.size.height as usize >> 3)
+    }
+}
+
+struct CacheEntry<const LEN: usize> {
+    key: Option<(SpriteId, usize)>,
+    tick: u32,
+    data: [u8; LEN],
+}
+
+pub struct FlashPack<F, const N: usize, const SCRATCH: usize, const CACHE: usize = 0> {
+    flash: F,
+    base: u32,
+    sprites: [Option<PackSprite>; N],
+    index: SpriteIndex,
+    scratch: [u8; SCRATCH],
+    cache: [CacheEntry<SCRATCH>; CACHE],
+    tick: u32,
+}
+
+impl<F, const N: usize, const SCRATCH: usize, const CACHE: usize> FlashPack<F, N, SCRATCH, CACHE>
+where
+    F: ReadNorFlash,
+{
+    pub fn open(mut flash: F, base: u32) -> Result<Self, StorageError> {
+        const { assert!(SCRATCH > 0, "scratch buffer must not be empty") };
+
+        let mut header = [0; PACK_HEADER_LEN];
+        read(&mut flash, base, &mut header)?;
+        if header[..4] != PACK_MAGIC || header[4] != PACK_VERSION {
+            return Err(StorageError::Format);
+        }
+        let count = header[5] as usize;
+        if count > N {
+            return Err(StorageError::Capacity);
+        }
+
+        let mut sprites = [None; N];
+        let mut index = SpriteIndex::new();
+        for (slot, sprite) in sprites.iter_mut().enumerate().take(count) {
+            let mut raw = [0; PACK_ENTRY_LEN];
+            let offset = base + (PACK_HEADER_LEN + slot * PACK_ENTRY_LEN) as u32;
+            read(&mut flash, offset, &mut raw)?;
+            let entry = PackSprite::parse(&raw)?;
+            if index.slot(entry.id).is_some() {
+                return Err(StorageError::Format);
+            }
+            index = index.insert(entry.id, slot);
+            *sprite = Some(entry);
+        }
+
+        Ok(Self {
+            flash,
+            base,
+            sprites,
+            index,
+            scratch: [0; SCRATCH],
+            cache: core::array::from_fn(|_| CacheEntry {
+                key: None,
+                tick: 0,
+                data: [0; SCRATCH],
+            }),
+            tick: 0,
+        })
+    }
+
+    pub fn release(self) -> F {
+        self.flash
+    }
+
+    pub fn sprite(&self, sprite_id: SpriteId) -> Option<&PackSprite> {
+        self.index
+            .slot(sprite_id)
+            .and_then(|slot| self.sprites[slot].as_ref())
+    }
+
+    pub fn glyph_index(
+        &mut self,
+        sprite_id: SpriteId,
+        glyph: Glyph,
+    ) -> Result<Option<usize>, StorageError> {
+        let sprite = match self.sprite(sprite_id) {
+            Some(sprite) => *sprite,
+            None => return Ok(None),
+        };
+        match sprite.glyphs {
+            PackGlyphs::Single => Ok(Some(0)),
+            PackGlyphs::Sequential(len) => Ok((u16::from(glyph) < len).then_some(glyph as usize)),
+            PackGlyphs::Alphabet(len, table) => {
+                let mut idx = [0];
+                read(&mut self.flash, self.base + table + glyph as u32, &mut idx)?;
+                Ok((u16::from(idx[0]) < len).then_some(idx[0] as usize))
+            }
+        }
+    }
+
+    fn cached(&mut self, key: (SpriteId, usize)) -> Option<usize> {
+        self.tick = self.tick.wrapping_add(1);
+        let tick = self.tick;
+        self.cache
+            .iter_mut()
+            .position(|entry| entry.key == Some(key))
+            .inspect(|slot| self.cache[*slot].tick = tick)
+    }
+
+    fn evict(&mut self) -> Option<usize> {
+        let tick = self.tick;
+        (0..CACHE).max_by_key(|slot| match self.cache[*slot].key {
+            Some(_) => tick.wrapping_sub(self.cache[*slot].tick),
+            None => u32::MAX,
+        })
+    }
+}
+
+impl<F, const N: usize, const SCRATCH: usize, const CACHE: usize> SpriteStore
+    for FlashPack<F, N, SCRATCH, CACHE>
+where
+    F: ReadNorFlash,
+{
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        self.sprite(sprite_id).map(|sprite| sprite.size)
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        let sprite = match self.sprite(req.sprite_id) {
+            Some(sprite) => *sprite,
+            None => return Ok(()),
+        };
+        let glyph_index = match self.glyph_index(req.sprite_id, req.glyph) {
+            Ok(Some(idx)) => idx,
+            Ok(None) => return Ok(()),
+            Err(err) => return Err(RenderError::Storage(err)),
+        };
+
+        let glyph_len = sprite.glyph_len();
+        let bitmap = self.base + sprite.bitmap + (glyph_index * glyph_len) as u32;
+        let bounds = Rectangle::new(req.origin, sprite.size);
+
+        if glyph_len <= SCRATCH {
+            let key = (req.sprite_id, glyph_index);
+            if let Some(slot) = self.cached(key) {
+                let data = &self.cache[slot].data[..glyph_len];
+                return canvas
+                    .try_draw_gray(bounds, data, BitDepth::Mono, req.style())
+                    .map_err(RenderError::Canvas);
+            }
+            let data = match self.evict() {
+                Some(slot) => {
+                    let entry = &mut self.cache[slot];
+                    entry.key = None;
+                    read(&mut self.flash, bitmap, &mut entry.data[..glyph_len])
+                        .map_err(RenderError::Storage)?;
+                    entry.key = Some(key);
+                    entry.tick = self.tick;
+                    &entry.data[..glyph_len]
+                }
+                None => {
+                    let data = &mut self.scratch[..glyph_len];
+                    read(&mut self.flash, bitmap, data).map_err(RenderError::Storage)?;
+                    data
+                }
+            };
+            return canvas
+                .try_draw_gray(bounds, data, BitDepth::Mono, req.style())
+                .map_err(RenderError::Canvas);
+        }
+
+        let flash = &mut self.flash;
+        draw_streamed(
+            canvas,
+            bounds,
+            req.style(),
+            &mut self.scratch,
+            |offset, data| read(flash, bitmap + offset as u32, data),
+        )
+    }
+}
+
+fn read<F: ReadNorFlash>(flash: &mut F, offset: u32, buf: &mut [u8]) -> Result<(), StorageError> {
+    let align = F::READ_SIZE.max(1);
+    if (offset as usize).is_multiple_of(align) && buf.len().is_multiple_of(align) {
+        return read_aligned(flash, offset, buf);
+    }
+
+    let window_len = READ_WINDOW - READ_WINDOW % align;
+    if window_len == 0 {
+        return Err(StorageError::NotAligned);
+    }
+
+    let mut window = [0; READ_WINDOW];
+    let mut offset = offset as usize;
+    let mut buf = buf;
+    while !buf.is_empty() {
+        let skip = offset % align;
+        let len = (skip + buf.len()).next_multiple_of(align).min(window_len);
+        read_aligned(flash, (offset - skip) as u32, &mut window[..len])?;
+        let (head, tail) = buf.split_at_mut(buf.len().min(len - skip));
+        head.copy_from_slice(&window[skip..skip + head.len()]);
+        offset += head.len();
+        buf = tail;
+    }
+    Ok(())
+}
+
+fn read_aligned<F: ReadNorFlash>(
+    flash: &mut F,
+    offset: u32,
+    buf: &mut [u8],
+) -> Result<(), StorageError> {
+    flash.read(offset, buf).map_err(|err| match err.kind() {
+        NorFlashErrorKind::NotAligned => StorageError::NotAligned,
+        NorFlashErrorKind::OutOfBounds => StorageError::OutOfBounds,
+        _ => StorageError::Other,
+    })
+}
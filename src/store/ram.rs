@@ -0,0 +1,137 @@
+use crate::*;
+
+pub struct RamSprite<const LEN: usize> {
+    id: SpriteId,
+    size: Size,
+    glyphs: usize,
+    glyph_len: usize,
+    bitmap: [u8; LEN],
+    invalidated: bool,
+}
+
+impl<const LEN: usize> RamSprite<LEN> {
+    pub const fn new(id: SpriteId, glyphs: usize, size: Size) -> Self {
+        match Self::try_new(id, glyphs, size) {
+            Ok(sprite) => sprite,
+            Err(err) => err.raise(),
+        }
+    }
+
+    pub const fn try_new(id: SpriteId, glyphs: usize, size: Size) -> Result<Self, SpriteError> {
+        let glyph_len = match SpriteError::validate(glyphs, size, LEN) {
+            Ok(glyph_len) => glyph_len,
+            Err(err) => return Err(err),
+        };
+        Ok(Self {
+            id,
+            size,
+            glyphs,
+            glyph_len,
+            bitmap: [0; LEN],
+            invalidated: true,
+        })
+    }
+
+    pub const fn id(&self) -> SpriteId {
+        self.id
+    }
+
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    pub const fn glyphs(&self) -> usize {
+        self.glyphs
+    }
+
+    pub fn raw(&self) -> &[u8] {
+        &self.bitmap
+    }
+
+    pub fn glyph_bitmap(&self, glyph_index: usize) -> Option<&[u8]> {
+        let offset = glyph_index * self.glyph_len;
+        self.bitmap.get(offset..(offset + self.glyph_len))
+    }
+
+    pub fn glyph_bitmap_mut(&mut self, glyph_index: usize) -> Option<&mut [u8]> {
+        let offset = glyph_index * self.glyph_len;
+        let bitmap = self.bitmap.get_mut(offset..(offset + self.glyph_len))?;
+        self.invalidated = true;
+        Some(bitmap)
+    }
+
+    pub fn update(&mut self, glyph_index: usize, bitmap: &[u8]) {
+        if let Some(glyph) = self.glyph_bitmap_mut(glyph_index) {
+            let len = glyph.len().min(bitmap.len());
+            glyph[..len].copy_from_slice(&bitmap[..len]);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.bitmap.fill(0);
+        self.invalidated = true;
+    }
+
+    pub fn pixel(&self, glyph_index: usize, x: usize, y: usize) -> bool {
+        self.offset(glyph_index, x, y)
+            .is_some_and(|offset| self.bitmap[offset] & (1 << (y & 7)) != 0)
+    }
+
+    pub fn set_pixel(&mut self, glyph_index: usize, x: usize, y: usize, on: bool) {
+        if let Some(offset) = self.offset(glyph_index, x, y) {
+            let mask = 1 << (y & 7);
+            let byte = if on {
+                self.bitmap[offset] | mask
+            } else {
+                self.bitmap[offset] & !mask
+            };
+            if self.bitmap[offset] != byte {
+                self.bitmap[offset] = byte;
+                self.invalidated = true;
+            }
+        }
+    }
+
+    pub fn is_invalidated(&self) -> bool {
+        self.invalidated
+    }
+
+    fn offset(&self, glyph_index: usize, x: usize, y: usize) -> Option<usize> {
+        let width = self.size.width as usize;
+        if glyph_index >= self.glyphs || x >= width || y >= self.size.height as usize {
+            return None;
+        }
+        Some(glyph_index * self.glyph_len + (y >> 3) * width + x)
+    }
+}
+
+impl<const LEN: usize> SpriteStore for RamSprite<LEN> {
+    fn sprite_size(&self, sprite_id: SpriteId) -> Option<Size> {
+        (sprite_id == self.id).then_some(self.size)
+    }
+
+    fn draw<C: TryCanvas>(
+        &mut self,
+        canvas: &mut C,
+        req: RenderRequest,
+    ) -> Result<(), RenderError<C::Error>> {
+        if req.sprite_id != self.id {
+            return Ok(());
+        }
+        match self.glyph_bitmap(req.glyph as usize) {
+            Some(bitmap) => canvas
+                .try_draw_gray(
+                    Rectangle::new(req.origin, self.size),
+                    bitmap,
+                    BitDepth::Mono,
+                    req.style(),
+                )
+                .map_err(RenderError::Canvas),
+            None => Ok(()),
+        }
+    }
+
+    fn take_invalidated(&mut self) -> Option<SpriteId> {
+        core::mem::replace(&mut self.invalidated, false).then_some(self.id)
+    }
+}
@@ -9,6 +9,9 @@ pub struct Grid<L: Layout, const LEN: usize> {
     origins: [Point; LEN],
     invalidate: [bool; LEN],
     cursor: usize,
+    level: GrayLevel,
+    palette: PaletteId,
+    attributes: Attributes,
 }
 
 impl<L: Layout, const LEN: usize> Grid<L, LEN>
@@ -39,6 +42,9 @@ where
             state,
             invalidate,
             cursor: 0,
+            level: MAX_GRAY_LEVEL,
+            palette: 0,
+            attributes: Attributes::NONE,
             sprite_id: sprite_id.into(),
             layout: PhantomData {},
         }
@@ -52,6 +58,39 @@ impl<L: Layout, const LEN: usize> Grid<L, LEN> {
             self.invalidate[idx] = true;
         }
     }
+
+    pub fn level(&self) -> GrayLevel {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: GrayLevel) {
+        if self.level != level {
+            self.level = level;
+            self.invalidate();
+        }
+    }
+
+    pub fn palette(&self) -> PaletteId {
+        self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: PaletteId) {
+        if self.palette != palette {
+            self.palette = palette;
+            self.invalidate();
+        }
+    }
+
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        if self.attributes != attributes {
+            self.attributes = attributes;
+            self.invalidate();
+        }
+    }
 }
 
 impl<L: Layout, const LEN: usize> Widget<&[Glyph; LEN]> for Grid<L, LEN> {
@@ -62,20 +101,31 @@ impl<L: Layout, const LEN: usize> Widget<&[Glyph; LEN]> for Grid<L, LEN> {
         self.cursor = 0;
     }
 
+    fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+        if self.sprite_id == sprite_id {
+            self.invalidate();
+        }
+    }
+
     fn invalidate(&mut self) {
         for invalidate in self.invalidate.iter_mut() {
             *invalidate = true;
         }
     }
 
-    fn render<D: Display>(&mut self, display: &mut D) {
+    fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
         for (idx, invalidate) in self.invalidate.iter_mut().enumerate() {
             if *invalidate {
                 let glyph = self.state[idx];
-                display.render(RenderRequest::new(self.origins[idx], self.sprite_id, glyph));
+                let req = RenderRequest::new(self.origins[idx], self.sprite_id, glyph)
+                    .with_level(self.level)
+                    .with_palette(self.palette)
+                    .with_attributes(self.attributes);
+                display.try_render(req)?;
                 *invalidate = false;
             }
         }
+        Ok(())
     }
 }
 
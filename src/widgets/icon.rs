@@ -5,6 +5,9 @@ pub struct Icon<S> {
     state: S,
     sprite_id: SpriteId,
     origin: Point,
+    level: GrayLevel,
+    palette: PaletteId,
+    attributes: Attributes,
     invalidate: bool,
 }
 
@@ -17,9 +20,45 @@ where
             origin,
             state,
             sprite_id: sprite_id.into(),
+            level: MAX_GRAY_LEVEL,
+            palette: 0,
+            attributes: Attributes::NONE,
             invalidate: true,
         }
     }
+
+    pub fn level(&self) -> GrayLevel {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: GrayLevel) {
+        if self.level != level {
+            self.level = level;
+            self.invalidate = true;
+        }
+    }
+
+    pub fn palette(&self) -> PaletteId {
+        self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: PaletteId) {
+        if self.palette != palette {
+            self.palette = palette;
+            self.invalidate = true;
+        }
+    }
+
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        if self.attributes != attributes {
+            self.attributes = attributes;
+            self.invalidate = true;
+        }
+    }
 }
 
 impl<S> Widget<S> for Icon<S>
@@ -37,14 +76,21 @@ where
         }
     }
 
-    fn render<D: Display>(&mut self, display: &mut D) {
+    fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+        if self.sprite_id == sprite_id {
+            self.invalidate();
+        }
+    }
+
+    fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
         if self.invalidate {
-            display.render(RenderRequest::new(
-                self.origin,
-                self.sprite_id,
-                self.state.into(),
-            ));
+            let req = RenderRequest::new(self.origin, self.sprite_id, self.state.into())
+                .with_level(self.level)
+                .with_palette(self.palette)
+                .with_attributes(self.attributes);
+            display.try_render(req)?;
             self.invalidate = false;
         }
+        Ok(())
     }
 }
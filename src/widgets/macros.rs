@@ -30,8 +30,12 @@ macro_rules! widget {
             self.child.invalidate();
         }
 
-        fn render<D: Display>(&mut self, display: &mut D) {
-            self.child.render(display);
+        fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+            self.child.invalidate_sprite(sprite_id);
+        }
+
+        fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
+            self.child.try_render(display)
         }
     }
 };
@@ -73,10 +77,17 @@ macro_rules! widget_group {
             )+
         }
 
-        fn render<D: Display>(&mut self, display: &mut D) {
+        fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+            $(
+                self.$node_name.invalidate_sprite(sprite_id);
+            )+
+        }
+
+        fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
             $(
-                self.$node_name.render(display);
+                self.$node_name.try_render(display)?;
             )+
+            Ok(())
         }
     }
 }
@@ -135,12 +146,19 @@ macro_rules! widget_mux {
                 )+
             }
 
-            fn render<D: Display>(&mut self, display: &mut D) {
+            fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+                $(
+                    self.$node_name.invalidate_sprite(sprite_id);
+                )+
+            }
+
+            fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
                 $(
                     if self.active == [<$name:camel Node>]::[<$node_name:camel>] {
-                        self.$node_name.render(display);
+                        self.$node_name.try_render(display)?;
                     }
                 )+
+                Ok(())
             }
         }
     }
@@ -3,18 +3,44 @@ use crate::*;
 mod grid;
 mod icon;
 mod macros;
+mod scroll;
 mod tile;
 
 pub use grid::*;
 pub use icon::*;
 pub use layout::*;
 pub use macros::*;
+pub use scroll::*;
 pub use tile::*;
 
 pub trait Widget<S> {
     fn invalidate(&mut self);
+
+    fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+        let _ = sprite_id;
+        self.invalidate();
+    }
     fn update(&mut self, state: S);
-    fn render<D: Display>(&mut self, render: &mut D);
+    fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error>;
+
+    fn render<D: Display>(&mut self, display: &mut D) {
+        self.try_render(&mut DisplayRef(display)).ok();
+    }
+}
+
+struct DisplayRef<'a, D: ?Sized>(&'a mut D);
+
+impl<D: Display + ?Sized> TryDisplay for DisplayRef<'_, D> {
+    type Error = core::convert::Infallible;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        self.0.render(req);
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        Ok(self.0.scroll(start_line))
+    }
 }
 
 pub type GlyphIcon = Icon<Glyph>;
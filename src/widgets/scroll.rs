@@ -0,0 +1,193 @@
+use crate::*;
+
+pub struct ScrollView<const COLS: usize, const ROWS: usize> {
+    sprite_id: SpriteId,
+    origin_x: Coord,
+    glyph_size: Size,
+    blank: Glyph,
+    lines: [[Glyph; COLS]; ROWS],
+    invalidate: [bool; ROWS],
+    len: usize,
+    top: usize,
+    head: usize,
+    cursor: usize,
+    new_line: bool,
+    scroll_lines: Option<Option<Coord>>,
+    scroll: Coord,
+    target: Coord,
+    step: Coord,
+}
+
+impl<const COLS: usize, const ROWS: usize> ScrollView<COLS, ROWS> {
+    pub fn new<SI: Into<SpriteId>>(
+        sprite_id: SI,
+        origin_x: Coord,
+        glyph_size: Size,
+        blank: Glyph,
+    ) -> Self {
+        const { assert!(ROWS > 1, "scroll view needs a spare row") };
+        Self {
+            origin_x,
+            glyph_size,
+            blank,
+            sprite_id: sprite_id.into(),
+            lines: [[blank; COLS]; ROWS],
+            invalidate: [true; ROWS],
+            len: 0,
+            top: 0,
+            head: 0,
+            cursor: 0,
+            new_line: true,
+            scroll_lines: None,
+            scroll: 0,
+            target: 0,
+            step: 0,
+        }
+    }
+
+    pub fn set_step(&mut self, step: Coord) {
+        self.step = step;
+    }
+
+    pub fn is_scrolling(&self) -> bool {
+        self.scroll != self.target
+    }
+
+    pub fn clear(&mut self) {
+        self.lines = [[self.blank; COLS]; ROWS];
+        self.len = 0;
+        self.top = 0;
+        self.head = 0;
+        self.cursor = 0;
+        self.new_line = true;
+        self.target = 0;
+        self.invalidate = [true; ROWS];
+    }
+
+    pub fn push_line(&mut self, line: &[Glyph]) {
+        let slot = self.next_slot();
+        for (idx, glyph) in self.lines[slot].iter_mut().enumerate() {
+            *glyph = line.get(idx).copied().unwrap_or(self.blank);
+        }
+        self.cursor = line.len().min(COLS);
+        self.new_line = true;
+    }
+
+    fn next_slot(&mut self) -> usize {
+        let slot = self.head;
+        self.head = (self.head + 1) % ROWS;
+        if self.len < ROWS - 1 {
+            self.len += 1;
+        } else {
+            self.lines[self.top] = [self.blank; COLS];
+            self.invalidate[self.top] = true;
+            self.top = (self.top + 1) % ROWS;
+            self.target = self.glyph_size.height * self.top as Coord;
+        }
+        self.invalidate[slot] = true;
+        slot
+    }
+
+    fn row(&self, slot: usize, hardware: bool) -> usize {
+        if hardware {
+            slot
+        } else {
+            (slot + ROWS - self.top) % ROWS
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Widget<&[Glyph]> for ScrollView<COLS, ROWS> {
+    fn invalidate(&mut self) {
+        self.invalidate = [true; ROWS];
+        self.scroll_lines = None;
+    }
+
+    fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+        if self.sprite_id == sprite_id {
+            self.invalidate();
+        }
+    }
+
+    fn update(&mut self, line: &[Glyph]) {
+        self.push_line(line);
+    }
+
+    fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
+        let lines = match self.scroll_lines {
+            Some(lines) => lines,
+            None => {
+                let height = ROWS as u32 * self.glyph_size.height as u32;
+                let lines = match display.try_scroll(self.scroll)? {
+                    Some(lines) if lines as u32 == height => Some(lines),
+                    Some(_) => {
+                        display.try_scroll(0)?;
+                        None
+                    }
+                    None => None,
+                };
+                self.scroll_lines = Some(lines);
+                lines
+            }
+        };
+
+        if lines.is_none() && self.scroll != self.target {
+            self.scroll = self.target;
+            self.invalidate = [true; ROWS];
+        }
+
+        for slot in 0..ROWS {
+            if !self.invalidate[slot] {
+                continue;
+            }
+            let y = self.glyph_size.height * self.row(slot, lines.is_some()) as Coord;
+            for (col, glyph) in self.lines[slot].iter().enumerate() {
+                let x = self.origin_x + self.glyph_size.width * col as Coord;
+                display.try_render(RenderRequest::new(Point::new(x, y), self.sprite_id, *glyph))?;
+            }
+            self.invalidate[slot] = false;
+        }
+
+        if let Some(lines) = lines {
+            if self.scroll != self.target {
+                let lines = lines as u32;
+                let distance = (self.target as u32 + lines - self.scroll as u32) % lines;
+                let step = match self.step {
+                    0 => distance,
+                    step => distance.min(step as u32),
+                };
+                let scroll = ((self.scroll as u32 + step) % lines) as Coord;
+                display.try_scroll(scroll)?;
+                self.scroll = scroll;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> core::fmt::Write for ScrollView<COLS, ROWS> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for glyph in s.bytes() {
+            if glyph == b'\n' {
+                if self.new_line {
+                    self.push_line(&[]);
+                }
+                self.new_line = true;
+                continue;
+            }
+            if self.new_line {
+                let slot = self.next_slot();
+                self.lines[slot] = [self.blank; COLS];
+                self.cursor = 0;
+                self.new_line = false;
+            }
+            if self.cursor < COLS {
+                let slot = (self.head + ROWS - 1) % ROWS;
+                self.lines[slot][self.cursor] = glyph;
+                self.invalidate[slot] = true;
+                self.cursor += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@ pub struct Tile<S> {
     columns: usize,
     rows: usize,
     sprite_size: Size,
+    level: GrayLevel,
+    palette: PaletteId,
+    attributes: Attributes,
     invalidate: bool,
 }
 
@@ -30,9 +33,45 @@ where
             rows,
             sprite_size,
             sprite_id: sprite_id.into(),
+            level: MAX_GRAY_LEVEL,
+            palette: 0,
+            attributes: Attributes::NONE,
             invalidate: true,
         }
     }
+
+    pub fn level(&self) -> GrayLevel {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: GrayLevel) {
+        if self.level != level {
+            self.level = level;
+            self.invalidate = true;
+        }
+    }
+
+    pub fn palette(&self) -> PaletteId {
+        self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: PaletteId) {
+        if self.palette != palette {
+            self.palette = palette;
+            self.invalidate = true;
+        }
+    }
+
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        if self.attributes != attributes {
+            self.attributes = attributes;
+            self.invalidate = true;
+        }
+    }
 }
 
 impl<S> Widget<S> for Tile<S>
@@ -50,19 +89,30 @@ where
         }
     }
 
-    fn render<D: Display>(&mut self, display: &mut D) {
+    fn invalidate_sprite(&mut self, sprite_id: SpriteId) {
+        if self.sprite_id == sprite_id {
+            self.invalidate();
+        }
+    }
+
+    fn try_render<D: TryDisplay>(&mut self, display: &mut D) -> Result<(), D::Error> {
         if self.invalidate {
             let glyph = self.state.into();
             for x in 0..self.columns {
                 for y in 0..self.rows {
                     let origin = Point::new(
-                        self.origin.x + self.sprite_size.width * x as u8,
-                        self.origin.y + self.sprite_size.height * y as u8,
+                        self.origin.x + self.sprite_size.width * x as Coord,
+                        self.origin.y + self.sprite_size.height * y as Coord,
                     );
-                    display.render(RenderRequest::new(origin, self.sprite_id, glyph));
+                    let req = RenderRequest::new(origin, self.sprite_id, glyph)
+                        .with_level(self.level)
+                        .with_palette(self.palette)
+                        .with_attributes(self.attributes);
+                    display.try_render(req)?;
                 }
             }
             self.invalidate = false;
         }
+        Ok(())
     }
 }
@@ -0,0 +1,89 @@
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Spans(Vec<Rectangle>);
+
+impl TryCanvas for Spans {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, _: &[u8]) -> Result<(), Self::Error> {
+        self.0.push(bounds);
+        Ok(())
+    }
+}
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+// 8x8 sprite with a single pixel at (1, 2).
+const DOT: [u8; 8] = [0, 1 << 2, 0, 0, 0, 0, 0, 0];
+
+fn with_attributes(attributes: Attributes) -> Vec<(usize, usize)> {
+    let mut fb = FrameBuffer::<24, 2>::new();
+    let sprite = rect(8, 0, 8, 8);
+    AttributeCanvas::new(&mut fb, sprite, attributes).draw(sprite, &DOT);
+
+    let mut pixels = Vec::new();
+    for y in 0..16 {
+        for x in 0..24 {
+            if fb.pixel(x, y) {
+                pixels.push((x, y));
+            }
+        }
+    }
+    pixels
+}
+
+#[test]
+fn attribute_canvas_transforms_sprite() {
+    assert_eq!(with_attributes(Attributes::NONE), [(9, 2)]);
+    assert_eq!(with_attributes(Attributes::FLIP_H), [(14, 2)]);
+    assert_eq!(with_attributes(Attributes::FLIP_V), [(9, 5)]);
+    assert_eq!(
+        with_attributes(Attributes::FLIP_H | Attributes::FLIP_V),
+        [(14, 5)]
+    );
+    assert_eq!(
+        with_attributes(Attributes::SCALE_2X),
+        [(10, 4), (11, 4), (10, 5), (11, 5)]
+    );
+
+    let inverted = with_attributes(Attributes::INVERT);
+    assert_eq!(inverted.len(), 63);
+    assert!(!inverted.contains(&(9, 2)));
+    assert!(inverted.iter().all(|(x, y)| (8..16).contains(x) && *y < 8));
+}
+
+#[test]
+fn scaled_sprite_past_the_edge_does_not_wrap() {
+    let sprite = rect(200, 0, 40, 8);
+    let mut canvas = Spans::default();
+    AttributeCanvas::new(&mut canvas, sprite, Attributes::SCALE_2X).draw(sprite, &[0xff; 40]);
+
+    assert!(!canvas.0.is_empty());
+    assert!(canvas.0.iter().all(|span| span.origin.x >= 200));
+    assert_eq!(canvas.0[0], rect(200, 0, 64, 16));
+}
+
+#[test]
+fn attributes_reach_the_canvas_through_sprite_display() {
+    const ARROW: FlashSprite = sprite!(1, ["#..", "##.", "#.."]);
+
+    let mut display = SpriteDisplay::new(FrameBuffer::<8, 1>::new(), [(ARROW, Glyphs::Single)]);
+    display.render(RenderRequest::new(Point::zero(), 1, 0).with_attributes(Attributes::FLIP_H));
+    assert_eq!(display.canvas().page(0)[..3], [0x00, 0x02, 0x07]);
+}
+
+#[test]
+fn attribute_bits() {
+    let attrs = Attributes::INVERT | Attributes::SCALE_2X;
+    assert_eq!(attrs.bits(), 0b1001);
+    assert!(attrs.contains(Attributes::SCALE_2X));
+    assert!(!attrs.contains(Attributes::FLIP_V));
+    assert_eq!(attrs.difference(Attributes::INVERT), Attributes::SCALE_2X);
+    assert_eq!(attrs.scale(), 2);
+    assert_eq!(Attributes::NONE.scale(), 1);
+    assert!(Attributes::from_bits(0).is_empty());
+}
@@ -0,0 +1,111 @@
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Spans(Vec<(Rectangle, Vec<u8>)>);
+
+impl TryCanvas for Spans {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.0.push((bounds, bitmap.to_vec()));
+        Ok(())
+    }
+}
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+fn spans<const W: usize, const PAGES: usize>(
+    canvas: &mut BufferedCanvas<Spans, W, PAGES>,
+) -> Vec<Rectangle> {
+    let spans = canvas
+        .canvas()
+        .0
+        .iter()
+        .map(|(bounds, _)| *bounds)
+        .collect();
+    canvas.canvas().0.clear();
+    spans
+}
+
+#[test]
+fn buffered_canvas_sends_whole_pages_first() {
+    let mut canvas = BufferedCanvas::<_, 32, 2>::new(Spans::default());
+    assert!(canvas.is_dirty());
+    canvas.flush().unwrap();
+
+    assert_eq!(spans(&mut canvas), [rect(0, 0, 32, 8), rect(0, 8, 32, 8)]);
+    assert!(!canvas.is_dirty());
+
+    canvas.invalidate();
+    assert!(canvas.is_dirty());
+    canvas.flush().unwrap();
+    assert_eq!(spans(&mut canvas).len(), 2);
+}
+
+#[test]
+fn buffered_canvas_sends_merged_diff_spans() {
+    let mut canvas = BufferedCanvas::<_, 32, 2>::new(Spans::default());
+    canvas.flush().unwrap();
+    canvas.canvas().0.clear();
+
+    let back = canvas.back();
+    back.set_pixel(2, 0, true);
+    back.set_pixel(5, 1, true);
+    back.set_pixel(12, 0, true);
+    back.set_pixel(20, 9, true);
+    assert!(canvas.is_dirty());
+    canvas.flush().unwrap();
+
+    assert_eq!(
+        canvas.canvas().0,
+        [
+            (rect(2, 0, 4, 8), vec![0x01, 0, 0, 0x02]),
+            (rect(12, 0, 1, 8), vec![0x01]),
+            (rect(20, 8, 1, 8), vec![0x02]),
+        ]
+    );
+    assert_eq!(canvas.front()[1][20], 0x02);
+
+    canvas.canvas().0.clear();
+    canvas.flush().unwrap();
+    assert!(canvas.canvas().0.is_empty());
+}
+
+#[test]
+fn buffered_canvas_respects_merge_gap() {
+    let mut canvas = BufferedCanvas::<_, 16, 1>::new(Spans::default());
+    canvas.flush().unwrap();
+    canvas.canvas().0.clear();
+    canvas.set_merge_gap(0);
+
+    canvas.back().set_pixel(2, 0, true);
+    canvas.back().set_pixel(4, 0, true);
+    canvas.flush().unwrap();
+
+    assert_eq!(spans(&mut canvas), [rect(2, 0, 1, 8), rect(4, 0, 1, 8)]);
+}
+
+#[test]
+fn buffered_canvas_collects_sprite_draws() {
+    const BLOCK: FlashSprite = sprite!(1, ["##", "##"]);
+
+    let mut display = SpriteDisplay::new(
+        BufferedCanvas::<_, 16, 2>::new(Spans::default()),
+        [(BLOCK, Glyphs::Single)],
+    );
+    display.canvas().flush().unwrap();
+    display.canvas().canvas().0.clear();
+
+    display.render(RenderRequest::new(Point::new(4, 8), 1, 0));
+    display.render(RenderRequest::new(Point::new(4, 8), 1, 0));
+    assert!(display.canvas().canvas().0.is_empty());
+
+    display.canvas().flush().unwrap();
+    assert_eq!(
+        display.canvas().canvas().0,
+        [(rect(4, 8, 2, 8), vec![0x03, 0x03])]
+    );
+}
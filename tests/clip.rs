@@ -0,0 +1,122 @@
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Spans(Vec<Rectangle>);
+
+impl TryCanvas for Spans {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, _: &[u8]) -> Result<(), Self::Error> {
+        self.0.push(bounds);
+        Ok(())
+    }
+}
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+#[test]
+fn rectangle_geometry() {
+    let area = rect(4, 8, 10, 6);
+    assert_eq!(area.end(), Point::new(14, 14));
+    assert!(area.contains(Point::new(4, 8)));
+    assert!(area.contains(Point::new(13, 13)));
+    assert!(!area.contains(Point::new(14, 13)));
+    assert!(!area.contains(Point::new(3, 8)));
+    assert!(rect(0, 0, 0, 4).is_empty());
+
+    assert_eq!(
+        area.intersect(&rect(10, 0, 20, 10)),
+        Some(rect(10, 8, 4, 2))
+    );
+    assert_eq!(area.intersect(&rect(14, 8, 4, 4)), None);
+    assert_eq!(area.intersect(&area), Some(area));
+
+    assert_eq!(area.clamp(Point::new(0, 20)), Point::new(4, 13));
+    assert_eq!(area.clamp(Point::new(6, 9)), Point::new(6, 9));
+
+    let edge = rect(Coord::MAX - 2, 0, 8, 8);
+    assert_eq!(edge.end(), Point::new(Coord::MAX, 8));
+    assert!(edge.contains(Point::new(Coord::MAX, 0)));
+}
+
+#[test]
+fn clip_canvas_drops_offscreen_sprites() {
+    let mut canvas = ClipCanvas::new(Spans::default(), Size::new(16, 16));
+    canvas.draw(rect(16, 0, 8, 8), &[0xff; 8]);
+    canvas.draw(rect(0, 16, 8, 8), &[0xff; 8]);
+    assert!(canvas.canvas().0.is_empty());
+
+    canvas.draw(rect(12, 8, 8, 8), &[0xff; 8]);
+    assert_eq!(canvas.canvas().0, [rect(12, 8, 4, 8)]);
+}
+
+#[test]
+fn clip_canvas_crops_sprites_on_the_edge() {
+    let bitmap: Vec<u8> = (0..16u8).map(|idx| idx.wrapping_mul(29) ^ 0x5a).collect();
+
+    let mut expected = FrameBuffer::<16, 2>::new();
+    expected.draw(rect(10, 5, 8, 16), &bitmap);
+
+    let mut clipped = ClipCanvas::new(FrameBuffer::<24, 3>::new(), Size::new(16, 16));
+    clipped.draw(rect(10, 5, 8, 16), &bitmap);
+    let clipped = clipped.release();
+
+    for y in 0..24 {
+        for x in 0..24 {
+            assert_eq!(
+                clipped.pixel(x, y),
+                expected.pixel(x, y),
+                "pixel ({}, {})",
+                x,
+                y
+            );
+        }
+    }
+}
+
+#[test]
+fn split_canvas_matches_single_framebuffer() {
+    let bitmap: Vec<u8> = (0..32u8).map(|idx| idx.wrapping_mul(37)).collect();
+    let bounds = rect(2, 3, 16, 13);
+
+    let mut expected = FrameBuffer::<24, 2>::new();
+    expected.draw(bounds, &bitmap);
+
+    let mut split = SplitCanvas::new([
+        (rect(0, 0, 10, 16), FrameBuffer::<10, 2>::new()),
+        (rect(10, 0, 10, 16), FrameBuffer::<10, 2>::new()),
+    ]);
+    split.draw(bounds, &bitmap);
+
+    let [(_, left), (_, right)] = split.release();
+    for y in 0..16 {
+        for x in 0..20 {
+            let actual = if x < 10 {
+                left.pixel(x, y)
+            } else {
+                right.pixel(x - 10, y)
+            };
+            assert_eq!(actual, expected.pixel(x, y), "pixel ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn split_canvas_drives_sprite_display() {
+    const BLOCK: FlashSprite = sprite!(1, ["####", "####"]);
+
+    let split = SplitCanvas::new([
+        (rect(0, 0, 8, 8), FrameBuffer::<8, 1>::new()),
+        (rect(8, 0, 8, 8), FrameBuffer::<8, 1>::new()),
+    ]);
+    let mut display = SpriteDisplay::new(split, [(BLOCK, Glyphs::Single)]);
+    display.render(RenderRequest::new(Point::new(6, 0), 1, 0));
+
+    let split = display.canvas();
+    assert_eq!(split.region(0).unwrap().page(0)[5..], [0, 0x03, 0x03]);
+    assert_eq!(split.region(1).unwrap().page(0)[..3], [0x03, 0x03, 0]);
+    assert!(split.region(2).is_none());
+}
@@ -0,0 +1,108 @@
+use klaptik::*;
+
+struct Bus {
+    sent: usize,
+    fail: bool,
+}
+
+impl TryDisplay for Bus {
+    type Error = u8;
+
+    fn try_render(&mut self, _: RenderRequest) -> Result<(), Self::Error> {
+        if self.fail {
+            return Err(0xee);
+        }
+        self.sent += 1;
+        Ok(())
+    }
+}
+
+fn req(glyph: Glyph) -> RenderRequest {
+    RenderRequest::new(Point::new(8, 0), 1, glyph)
+}
+
+#[test]
+fn mirror_displays_with_different_errors() {
+    let mut mirror = (
+        RecordingDisplay::<4>::new(),
+        Bus {
+            sent: 0,
+            fail: false,
+        },
+    );
+    mirror.render(req(1));
+    mirror.render(req(2));
+
+    mirror.0.assert_redraws(2);
+    mirror.0.assert_glyph_at(Point::new(8, 0), 1, 2);
+    assert_eq!(mirror.1.sent, 2);
+}
+
+#[test]
+fn mirror_reports_first_error_and_renders_all() {
+    let mut mirror = (
+        Bus {
+            sent: 0,
+            fail: true,
+        },
+        RecordingDisplay::<4>::new(),
+        Bus {
+            sent: 0,
+            fail: false,
+        },
+    );
+    assert_eq!(mirror.try_render(req(1)), Err(Either::Left(0xee)));
+    mirror.1.assert_redraws(1);
+    assert_eq!(mirror.2.sent, 1);
+
+    mirror.0.fail = false;
+    mirror.2.fail = true;
+    assert_eq!(
+        mirror.try_render(req(2)),
+        Err(Either::Right(Either::Right(0xee)))
+    );
+    assert_eq!(mirror.0.sent, 1);
+    mirror.1.assert_redraws(2);
+}
+
+#[test]
+fn fallible_adapts_infallible_display() {
+    struct Plain(usize);
+
+    impl Display for Plain {
+        fn render(&mut self, _: RenderRequest) {
+            self.0 += 1;
+        }
+    }
+
+    let mut display = Fallible(Plain(0));
+    assert_eq!(display.try_render(req(1)), Ok(()));
+    assert_eq!(display.try_scroll(0), Ok(None));
+    assert_eq!(display.0 .0, 1);
+
+    let mut icon = GlyphIcon::new(1, 3, Point::zero());
+    let mut plain = Plain(0);
+    icon.render(&mut plain);
+    assert_eq!(plain.0, 1);
+}
+
+#[test]
+fn palette_display_assigns_palettes_per_sprite() {
+    let mut display = PaletteDisplay::new(RecordingDisplay::<4>::new(), [(1, 2), (5, 7)]);
+    assert_eq!(display.palette(5), Some(7));
+    assert_eq!(display.palette(3), None);
+
+    display.set_palette(5, 4);
+    display.set_palette(3, 9);
+    display.render(RenderRequest::new(Point::zero(), 1, 0));
+    display.render(RenderRequest::new(Point::new(8, 0), 5, 0).with_palette(1));
+    display.render(RenderRequest::new(Point::new(16, 0), 3, 0).with_palette(6));
+
+    let palettes: Vec<_> = display
+        .release()
+        .requests()
+        .iter()
+        .map(|req| req.palette)
+        .collect();
+    assert_eq!(palettes, [2, 4, 6]);
+}
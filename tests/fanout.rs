@@ -0,0 +1,101 @@
+use klaptik::*;
+
+struct Bus {
+    sent: usize,
+    lines: Option<Coord>,
+    start_line: Coord,
+}
+
+impl Bus {
+    fn new(lines: Option<Coord>) -> Self {
+        Self {
+            sent: 0,
+            lines,
+            start_line: 0,
+        }
+    }
+}
+
+impl TryDisplay for Bus {
+    type Error = u8;
+
+    fn try_render(&mut self, _: RenderRequest) -> Result<(), Self::Error> {
+        self.sent += 1;
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.start_line = start_line;
+        Ok(self.lines)
+    }
+}
+
+struct Plain(Vec<RenderRequest>);
+
+impl Display for Plain {
+    fn render(&mut self, req: RenderRequest) {
+        self.0.push(req);
+    }
+}
+
+fn req(glyph: Glyph) -> RenderRequest {
+    RenderRequest::new(Point::new(8, 0), 1, glyph)
+}
+
+#[test]
+fn fan_out_to_displays_of_different_types() {
+    let mut recording = RecordingDisplay::<4>::new();
+    let mut bus = Bus::new(None);
+    let mut plain = Plain(Vec::new());
+
+    let mut fan: [&mut dyn Display; 3] = [&mut recording, &mut bus, &mut plain];
+    fan.render(req(1));
+    fan[..2].render(req(2));
+
+    recording.assert_redraws(2);
+    recording.assert_glyph_at(Point::new(8, 0), 1, 2);
+    assert_eq!(bus.sent, 2);
+    assert_eq!(plain.0, [req(1)]);
+}
+
+#[test]
+fn fan_out_drives_widgets() {
+    let mut first = RecordingDisplay::<4>::new();
+    let mut second = Bus::new(None);
+    let mut icon = GlyphIcon::new(1, 3, Point::zero());
+    {
+        let mut fan: [&mut dyn Display; 2] = [&mut first, &mut second];
+        icon.render(&mut fan);
+    }
+    first.assert_glyph_at(Point::zero(), 1, 3);
+    assert_eq!(second.sent, 1);
+}
+
+#[test]
+fn fan_out_scrolls_when_all_panels_agree() {
+    let mut a = Bus::new(Some(64));
+    let mut b = Bus::new(Some(64));
+    let mut c = Bus::new(None);
+
+    assert_eq!([&mut a as &mut dyn Display, &mut b].scroll(8), Some(64));
+    assert_eq!((a.start_line, b.start_line), (8, 8));
+
+    assert_eq!(
+        [&mut a as &mut dyn Display, &mut b, &mut c].scroll(16),
+        None
+    );
+    assert_eq!((a.start_line, b.start_line, c.start_line), (16, 16, 16));
+
+    let empty: &mut [&mut dyn Display] = &mut [];
+    assert_eq!(empty.scroll(0), None);
+}
+
+#[test]
+fn fan_out_to_arrays_of_displays() {
+    let mut fan = [RecordingDisplay::<4>::new(), RecordingDisplay::<4>::new()];
+    fan.render(req(5));
+    fan[1..].render(req(6));
+    fan[0].assert_redraws(1);
+    fan[1].assert_redraws(2);
+    fan[1].assert_glyph_at(Point::new(8, 0), 1, 6);
+}
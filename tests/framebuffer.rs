@@ -0,0 +1,66 @@
+use klaptik::*;
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+#[test]
+fn aligned_draw_copies_pages() {
+    let mut fb = FrameBuffer::<8, 2>::new();
+    fb.draw(rect(2, 8, 3, 8), &[0x01, 0x80, 0xff]);
+
+    assert_eq!(fb.page(0), &[0; 8]);
+    assert_eq!(fb.page(1), &[0, 0, 0x01, 0x80, 0xff, 0, 0, 0]);
+    assert!(fb.pixel(2, 8));
+    assert!(fb.pixel(3, 15));
+    assert!(!fb.pixel(3, 14));
+}
+
+#[test]
+fn unaligned_draw_sets_pixels() {
+    let mut fb = FrameBuffer::<4, 2>::new();
+    fb.fill(true);
+    fb.draw(rect(1, 5, 2, 6), &[0b10_1010, 0b01_0101]);
+
+    assert_eq!(
+        fb.ascii().to_string(),
+        "####\n####\n####\n####\n####\n#.##\n##.#\n#.##\n##.#\n#.##\n##.#\n####\n####\n####\n####\n####\n"
+    );
+}
+
+#[test]
+fn draw_is_clipped_to_buffer() {
+    let mut fb = FrameBuffer::<4, 1>::new();
+    fb.draw(rect(2, 0, 4, 8), &[1, 2, 4, 8]);
+    fb.draw(rect(0, 4, 1, 8), &[0xff]);
+    fb.draw(rect(0, 0, 0, 8), &[0xff]);
+
+    assert_eq!(fb.page(0), &[0xf0, 0, 1, 2]);
+    assert!(!fb.pixel(4, 0));
+    assert!(!fb.pixel(0, 8));
+}
+
+#[test]
+fn pbm_dump() {
+    let mut fb = FrameBuffer::<3, 1>::new();
+    fb.set_pixel(0, 0, true);
+    fb.set_pixel(2, 7, true);
+
+    let pbm = fb.pbm().to_string();
+    let mut lines = pbm.lines();
+    assert_eq!(lines.next(), Some("P1"));
+    assert_eq!(lines.next(), Some("3 8"));
+    assert_eq!(lines.next(), Some("100"));
+    assert_eq!(lines.clone().count(), 7);
+    assert_eq!(lines.last(), Some("001"));
+}
+
+#[test]
+fn pbm_dump_wraps_long_rows() {
+    let fb = FrameBuffer::<75, 1>::new();
+    let pbm = fb.pbm().to_string();
+    let rows: Vec<_> = pbm.lines().skip(2).collect();
+    assert_eq!(rows.len(), 16);
+    assert_eq!(rows[0].len(), 70);
+    assert_eq!(rows[1].len(), 5);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "fx")]
+
+use embedded_hal::blocking::i2c;
+use klaptik::drivers::fx::FxDisplay;
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Bus(Vec<(u8, Vec<u8>)>);
+
+impl i2c::Write for Bus {
+    type Error = Infallible;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.push((address, bytes.to_vec()));
+        Ok(())
+    }
+}
+
+static INDEX: GlyphIndex = GlyphIndex::new(b"0123456789");
+
+#[test]
+fn fx_display_remaps_glyphs() {
+    let mut display = FxDisplay::<_, 0x10, 2>::new(
+        Bus::default(),
+        [(3, Glyphs::Indexed(&INDEX)), (5, Glyphs::Alphabet(b"ab"))],
+    );
+    display.render(RenderRequest::new(Point::new(8, 0), 3, b'7'));
+    display.render(RenderRequest::new(Point::new(16, 0), 5, b'b'));
+    display.render(RenderRequest::new(Point::new(24, 0), 9, b'q'));
+
+    let packets: Vec<_> = display.release().0;
+    let coords = |x: u8| Coord::from(x).to_le_bytes().to_vec();
+    let packet = |x: u8, sprite_id: u8, glyph: u8| {
+        let mut bytes = coords(x);
+        bytes.extend(coords(0));
+        bytes.extend([sprite_id, glyph]);
+        (0x11, bytes)
+    };
+    assert_eq!(
+        packets,
+        [packet(8, 3, 7), packet(16, 5, 1), packet(24, 9, b'q')]
+    );
+}
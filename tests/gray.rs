@@ -0,0 +1,92 @@
+use klaptik::*;
+
+// Gray2 4x8 glyph with a 0..=3 ramp on the top row; plane 0 holds the high bit.
+static RAMP: [u8; 8] = [0, 0, 1, 1, 0, 1, 0, 1];
+
+fn ramp() -> FlashSprite {
+    FlashSprite::gray(2, 1, Size::new(4, 8), BitDepth::Gray2, &RAMP)
+}
+
+#[test]
+fn bit_depth_levels() {
+    let size = Size::new(4, 8);
+    let levels: Vec<_> = (0..4)
+        .map(|x| BitDepth::Gray2.level(&RAMP, size, x, 0))
+        .collect();
+    assert_eq!(levels, [0, 5, 10, 15]);
+    assert_eq!(BitDepth::Gray2.level(&RAMP, size, 3, 1), 0);
+    assert_eq!(BitDepth::Mono.level(&RAMP, size, 2, 0), MAX_GRAY_LEVEL);
+    assert_eq!(BitDepth::Gray4.level(&[1; 4], Size::new(1, 8), 0, 0), 15);
+    assert_eq!(BitDepth::Gray4.bitmap_len(Size::new(3, 16)), 24);
+}
+
+#[test]
+fn gray_sprite_geometry_is_validated() {
+    assert!(FlashSprite::try_gray(2, 1, Size::new(4, 8), BitDepth::Gray2, &RAMP).is_ok());
+    assert_eq!(
+        FlashSprite::try_gray(2, 1, Size::new(4, 8), BitDepth::Gray4, &RAMP).err(),
+        Some(SpriteError::BitmapLength)
+    );
+    assert_eq!(ramp().depth(), BitDepth::Gray2);
+    assert_eq!(ramp().glyph_bitmap(0), Some(&RAMP[..]));
+}
+
+#[test]
+fn gray_framebuffer_scales_by_level() {
+    let mut display =
+        SpriteDisplay::new(GrayFrameBuffer::<4, 8>::new(), [(ramp(), Glyphs::Single)]);
+    display.render(RenderRequest::new(Point::new(1, 0), 2, 0));
+    display.render(RenderRequest::new(Point::new(1, 1), 2, 0).with_level(7));
+
+    let canvas = display.canvas();
+    let row = |y| (0..8).map(|x| canvas.pixel(x, y)).collect::<Vec<_>>();
+    assert_eq!(row(0), [0, 0, 5, 10, 15, 0, 0, 0]);
+    assert_eq!(row(1), [0, 0, 2, 4, 7, 0, 0, 0]);
+    assert_eq!(canvas.row(0), &[0x00, 0x5a, 0xf0, 0x00]);
+}
+
+#[test]
+fn gray_framebuffer_draws_mono_at_full_level() {
+    let mut fb = GrayFrameBuffer::<2, 8>::new();
+    fb.fill(3);
+    fb.draw(
+        Rectangle::new(Point::new(1, 0), Size::new(2, 8)),
+        &[0x01, 0x02],
+    );
+    assert_eq!(fb.pixel(0, 0), 3);
+    assert_eq!(fb.pixel(1, 0), 15);
+    assert_eq!(fb.pixel(1, 1), 0);
+    assert_eq!(fb.pixel(2, 1), 15);
+    assert_eq!(fb.pixel(8, 0), 0);
+
+    let pgm = fb.pgm().to_string();
+    let mut lines = pgm.lines();
+    assert_eq!(lines.next(), Some("P2"));
+    assert_eq!(lines.next(), Some("4 8"));
+    assert_eq!(lines.next(), Some("15"));
+    assert_eq!(lines.next(), Some("3 15 0 3"));
+}
+
+#[test]
+fn mono_canvas_keeps_high_plane() {
+    let mut display = SpriteDisplay::new(FrameBuffer::<4, 1>::new(), [(ramp(), Glyphs::Single)]);
+    display.render(RenderRequest::new(Point::zero(), 2, 0));
+    assert_eq!(display.canvas().page(0), &[0, 0, 1, 1]);
+}
+
+#[test]
+fn widgets_carry_gray_level() {
+    let mut display = RecordingDisplay::<4>::new();
+    let mut label = Label::<2>::new(1, "ab", Point::zero(), Size::new(6, 8));
+    label.set_level(20);
+    label.render(&mut display);
+    assert!(display
+        .requests()
+        .iter()
+        .all(|req| req.level == MAX_GRAY_LEVEL));
+
+    label.set_level(4);
+    label.render(&mut display);
+    display.assert_redraws(4);
+    assert_eq!(display.last_at(Point::zero()).unwrap().level, 4);
+}
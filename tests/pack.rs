@@ -0,0 +1,165 @@
+#![cfg(feature = "storage")]
+
+use embedded_storage::nor_flash::{ErrorType, NorFlashError, NorFlashErrorKind, ReadNorFlash};
+use klaptik::*;
+use klaptik_codegen::{Bitmap, Sprite, SpritePack};
+
+#[derive(Debug)]
+struct FlashError(NorFlashErrorKind);
+
+impl NorFlashError for FlashError {
+    fn kind(&self) -> NorFlashErrorKind {
+        self.0
+    }
+}
+
+struct Flash<const READ_SIZE: usize>(Vec<u8>);
+
+impl<const READ_SIZE: usize> Flash<READ_SIZE> {
+    fn new(base: usize, pack: &[u8]) -> Self {
+        let mut data = vec![0xff; base];
+        data.extend_from_slice(pack);
+        data.resize(data.len().next_multiple_of(64), 0xff);
+        Self(data)
+    }
+}
+
+impl<const READ_SIZE: usize> ErrorType for Flash<READ_SIZE> {
+    type Error = FlashError;
+}
+
+impl<const READ_SIZE: usize> ReadNorFlash for Flash<READ_SIZE> {
+    const READ_SIZE: usize = READ_SIZE;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        if !offset.is_multiple_of(READ_SIZE) || !bytes.len().is_multiple_of(READ_SIZE) {
+            return Err(FlashError(NorFlashErrorKind::NotAligned));
+        }
+        let data = self
+            .0
+            .get(offset..offset + bytes.len())
+            .ok_or(FlashError(NorFlashErrorKind::OutOfBounds))?;
+        bytes.copy_from_slice(data);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn strip(width: usize, height: usize, seed: usize) -> Bitmap {
+    let mut bitmap = Bitmap::new(width, height);
+    for x in 0..width {
+        for y in 0..height {
+            bitmap.set_pixel(x, y, (x * 7 + y * 3 + seed).is_multiple_of(5));
+        }
+    }
+    bitmap
+}
+
+fn pack() -> (Vec<u8>, Sprite, Sprite) {
+    let font = Sprite::from_strip(&strip(18, 8, 0), 3).unwrap();
+    let logo = Sprite::from_strip(&strip(20, 16, 1), 1).unwrap();
+    let mut pack = SpritePack::new();
+    pack.add(
+        1,
+        font.clone(),
+        klaptik_codegen::PackGlyphs::Alphabet(b"abc".to_vec()),
+    )
+    .unwrap();
+    pack.add(2, logo.clone(), klaptik_codegen::PackGlyphs::Single)
+        .unwrap();
+    (pack.to_bytes(), font, logo)
+}
+
+fn open<const READ_SIZE: usize, const N: usize>(
+    bytes: &[u8],
+) -> Result<FlashPack<Flash<READ_SIZE>, N, 16>, StorageError> {
+    FlashPack::open(Flash::<READ_SIZE>::new(3, bytes), 3)
+}
+
+#[test]
+fn pack_layout_matches_packer() {
+    assert_eq!(PACK_MAGIC, klaptik_codegen::PACK_MAGIC);
+    assert_eq!(PACK_VERSION, klaptik_codegen::PACK_VERSION);
+    assert_eq!(PACK_HEADER_LEN, klaptik_codegen::PACK_HEADER_LEN);
+    assert_eq!(PACK_ENTRY_LEN, klaptik_codegen::PACK_ENTRY_LEN);
+}
+
+#[test]
+fn pack_header_is_parsed() {
+    let (bytes, _, _) = pack();
+    let mut pack = open::<1, 4>(&bytes).unwrap();
+
+    let font = *pack.sprite(1).unwrap();
+    assert_eq!(font.size, Size::new(6, 8));
+    assert!(matches!(font.glyphs, PackGlyphs::Alphabet(3, _)));
+    assert_eq!(pack.sprite(2).unwrap().size, Size::new(20, 16));
+    assert_eq!(pack.sprite(2).unwrap().glyphs, PackGlyphs::Single);
+    assert!(pack.sprite(3).is_none());
+
+    assert_eq!(pack.glyph_index(1, b'c'), Ok(Some(2)));
+    assert_eq!(pack.glyph_index(1, b'z'), Ok(None));
+    assert_eq!(pack.glyph_index(2, 42), Ok(Some(0)));
+    assert_eq!(pack.glyph_index(3, 0), Ok(None));
+}
+
+#[test]
+fn pack_header_is_validated() {
+    let (bytes, _, _) = pack();
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    assert_eq!(open::<1, 4>(&bad_magic).err(), Some(StorageError::Format));
+
+    let mut bad_version = bytes.clone();
+    bad_version[4] = PACK_VERSION + 1;
+    assert_eq!(open::<1, 4>(&bad_version).err(), Some(StorageError::Format));
+
+    assert_eq!(open::<1, 1>(&bytes).err(), Some(StorageError::Capacity));
+
+    let mut duplicate = bytes.clone();
+    duplicate[PACK_HEADER_LEN + PACK_ENTRY_LEN] = 1;
+    assert_eq!(open::<1, 4>(&duplicate).err(), Some(StorageError::Format));
+
+    let mut no_glyphs = bytes.clone();
+    no_glyphs[PACK_HEADER_LEN + 2] = 0;
+    assert_eq!(open::<1, 4>(&no_glyphs).err(), Some(StorageError::Format));
+
+    let mut unaligned = bytes;
+    unaligned[PACK_HEADER_LEN + 6] = 12;
+    assert_eq!(open::<1, 4>(&unaligned).err(), Some(StorageError::Format));
+}
+
+fn render<const READ_SIZE: usize>(bytes: &[u8]) -> FrameBuffer<32, 3> {
+    let pack = open::<READ_SIZE, 4>(bytes).unwrap();
+    let mut display = SpriteDisplay::new(FrameBuffer::<32, 3>::new(), pack);
+    display.render(RenderRequest::new(Point::new(1, 0), 1, b'b'));
+    display.render(RenderRequest::new(Point::new(3, 8), 2, 0));
+    std::mem::take(display.canvas())
+}
+
+#[test]
+fn pack_renders_with_aligned_reads() {
+    let (bytes, font, logo) = pack();
+
+    let mut expected = FrameBuffer::<32, 3>::new();
+    expected.draw(
+        Rectangle::new(Point::new(1, 0), Size::new(6, 8)),
+        &font.bitmap[6..12],
+    );
+    expected.draw(
+        Rectangle::new(Point::new(3, 8), Size::new(20, 16)),
+        &logo.bitmap,
+    );
+
+    for actual in [
+        render::<1>(&bytes),
+        render::<4>(&bytes),
+        render::<16>(&bytes),
+    ] {
+        assert_eq!(actual.pages(), expected.pages());
+    }
+}
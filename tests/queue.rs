@@ -0,0 +1,99 @@
+use klaptik::*;
+
+fn req(x: Coord, y: Coord, glyph: Glyph) -> RenderRequest {
+    RenderRequest::new(Point::new(x, y), 1, glyph)
+}
+
+#[test]
+fn queue_orders_by_page_then_column() {
+    let mut queue = RenderQueue::<_, 8>::new(RecordingDisplay::<8>::new());
+    queue.render(req(16, 8, 0));
+    queue.render(req(0, 12, 1));
+    queue.render(req(8, 0, 2));
+    queue.render(req(0, 0, 3));
+    queue.render(req(0, 4, 4));
+
+    let origins: Vec<_> = queue.requests().iter().map(|req| req.origin).collect();
+    assert_eq!(
+        origins,
+        [
+            Point::new(0, 0),
+            Point::new(0, 4),
+            Point::new(8, 0),
+            Point::new(0, 12),
+            Point::new(16, 8),
+        ]
+    );
+}
+
+#[test]
+fn queue_replaces_pending_request() {
+    let mut queue = RenderQueue::<_, 4>::new(RecordingDisplay::<4>::new());
+    queue.render(req(0, 0, 1));
+    queue.render(req(8, 0, 2));
+    queue.render(req(0, 0, 3));
+    assert_eq!(queue.len(), 2);
+
+    queue.flush().unwrap();
+    assert!(queue.is_empty());
+
+    let display = queue.display();
+    display.assert_redraws(2);
+    display.assert_glyph_at(Point::zero(), 1, 3);
+    display.assert_glyph_at(Point::new(8, 0), 1, 2);
+    display.assert_no_redundant();
+}
+
+#[test]
+fn queue_reports_overflow() {
+    let mut queue = RenderQueue::<_, 2>::new(RecordingDisplay::<2>::new());
+    assert_eq!(queue.try_render(req(0, 0, 1)), Ok(()));
+    assert_eq!(queue.try_render(req(8, 0, 2)), Ok(()));
+    assert!(queue.is_full());
+
+    assert_eq!(queue.try_render(req(0, 0, 3)), Ok(()));
+    assert_eq!(
+        queue.try_render(req(16, 0, 4)),
+        Err(QueueOverflow(req(16, 0, 4)))
+    );
+    assert_eq!(queue.len(), 2);
+}
+
+struct Flaky {
+    sent: Vec<RenderRequest>,
+    budget: usize,
+}
+
+impl TryDisplay for Flaky {
+    type Error = ();
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        if self.budget == 0 {
+            return Err(());
+        }
+        self.budget -= 1;
+        self.sent.push(req);
+        Ok(())
+    }
+}
+
+#[test]
+fn queue_keeps_unsent_requests_on_error() {
+    let display = Flaky {
+        sent: Vec::new(),
+        budget: 2,
+    };
+    let mut queue = RenderQueue::<_, 4>::new(display);
+    for x in 0..4 {
+        queue.render(req(x * 8, 0, x as Glyph));
+    }
+
+    assert_eq!(queue.flush(), Err(()));
+    assert_eq!(queue.display().sent, [req(0, 0, 0), req(8, 0, 1)]);
+    assert_eq!(queue.requests(), [req(16, 0, 2), req(24, 0, 3)]);
+
+    queue.display().budget = 8;
+    assert_eq!(queue.flush(), Ok(()));
+    assert!(queue.is_empty());
+    assert_eq!(queue.release().sent.len(), 4);
+}
@@ -0,0 +1,81 @@
+use klaptik::*;
+
+const BLOCK: FlashSprite = sprite!(1, ["##", "##"]);
+
+#[test]
+fn ram_sprite_pixels() {
+    let mut sprite = RamSprite::<16>::new(7, 2, Size::new(8, 8));
+    sprite.set_pixel(1, 2, 3, true);
+    assert!(sprite.pixel(1, 2, 3));
+    assert!(!sprite.pixel(0, 2, 3));
+    assert_eq!(sprite.glyph_bitmap(1).unwrap()[2], 0x08);
+
+    sprite.set_pixel(2, 0, 0, true);
+    sprite.set_pixel(0, 8, 0, true);
+    assert!(sprite
+        .raw()
+        .iter()
+        .enumerate()
+        .all(|(idx, byte)| (idx == 10) == (*byte != 0)));
+
+    sprite.update(0, &[0xff, 0x81]);
+    assert_eq!(&sprite.raw()[..3], &[0xff, 0x81, 0]);
+    sprite.clear();
+    assert!(sprite.raw().iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn ram_sprite_geometry_is_validated() {
+    assert_eq!(
+        RamSprite::<15>::try_new(7, 2, Size::new(8, 8)).err(),
+        Some(SpriteError::BitmapLength)
+    );
+    assert_eq!(
+        RamSprite::<16>::try_new(7, 0, Size::new(8, 8)).err(),
+        Some(SpriteError::NoGlyphs)
+    );
+}
+
+#[test]
+fn ram_sprite_reports_invalidation_once() {
+    let mut sprite = RamSprite::<8>::new(7, 1, Size::new(8, 8));
+    assert_eq!(sprite.take_invalidated(), Some(7));
+    assert_eq!(sprite.take_invalidated(), None);
+
+    sprite.set_pixel(0, 0, 0, false);
+    assert!(!sprite.is_invalidated());
+    sprite.set_pixel(0, 0, 0, true);
+    assert_eq!(sprite.take_invalidated(), Some(7));
+    assert_eq!(sprite.take_invalidated(), None);
+}
+
+#[test]
+fn invalidated_ram_sprite_redraws_its_widgets() {
+    let sprites = (
+        SpriteMap::new([(BLOCK, Glyphs::Single)]),
+        RamSprite::<8>::new(7, 1, Size::new(8, 8)),
+    );
+    let mut display = SpriteDisplay::new(FrameBuffer::<16, 1>::new(), sprites);
+    let mut block = GlyphIcon::new(1, 0, Point::zero());
+    let mut dynamic = GlyphIcon::new(7, 0, Point::new(8, 0));
+
+    while let Some(sprite_id) = display.take_invalidated() {
+        block.invalidate_sprite(sprite_id);
+        dynamic.invalidate_sprite(sprite_id);
+    }
+    block.render(&mut display);
+    dynamic.render(&mut display);
+    assert_eq!(display.canvas().page(0)[..3], [0x03, 0x03, 0]);
+    assert_eq!(display.canvas().page(0)[8], 0);
+
+    display.sprites().1.set_pixel(0, 0, 0, true);
+    display.canvas().clear();
+    while let Some(sprite_id) = display.take_invalidated() {
+        block.invalidate_sprite(sprite_id);
+        dynamic.invalidate_sprite(sprite_id);
+    }
+    block.render(&mut display);
+    dynamic.render(&mut display);
+    assert_eq!(display.canvas().page(0)[..3], [0, 0, 0]);
+    assert_eq!(display.canvas().page(0)[8], 0x01);
+}
@@ -0,0 +1,54 @@
+use klaptik::*;
+
+#[test]
+fn plain_packet_round_trip() {
+    let req = RenderRequest::new(Point::new(120, 56), 7, b'x');
+    let bytes = req.as_bytes();
+    assert_eq!(bytes.len(), RenderRequest::LEN);
+    assert_eq!(RenderRequest::from_bytes(&bytes), req);
+}
+
+#[cfg(not(feature = "wide"))]
+#[test]
+fn narrow_packet_layout() {
+    let req = RenderRequest::new(Point::new(120, 56), 7, b'x');
+    assert_eq!(req.as_bytes(), [120, 56, 7, b'x']);
+}
+
+#[cfg(feature = "wide")]
+#[test]
+fn wide_packet_layout() {
+    let req = RenderRequest::new(Point::new(480, 300), 7, b'x');
+    assert_eq!(req.as_bytes(), [0xe0, 0x01, 0x2c, 0x01, 7, b'x']);
+    assert_eq!(RenderRequest::from_bytes(&req.as_bytes()), req);
+
+    let edge = RenderRequest::new(Point::new(Coord::MAX, 0x0100), 0, 0);
+    assert_eq!(RenderRequest::from_bytes(&edge.as_bytes()), edge);
+}
+
+#[test]
+fn styled_requests_use_extended_packet() {
+    let plain = RenderRequest::new(Point::new(3, 4), 2, 9);
+    assert!(plain.is_plain());
+    assert!(!plain.with_level(3).is_plain());
+    assert!(!plain.with_palette(1).is_plain());
+    assert!(!plain.with_attributes(Attributes::INVERT).is_plain());
+
+    let req = plain
+        .with_level(5)
+        .with_palette(6)
+        .with_attributes(Attributes::FLIP_H | Attributes::SCALE_2X);
+    let bytes = req.as_ext_bytes();
+    assert_eq!(bytes.len(), RenderRequest::EXT_LEN);
+    assert_eq!(bytes[..RenderRequest::LEN], plain.as_bytes());
+    assert_eq!(bytes[RenderRequest::LEN..], [0xa5, 6]);
+    assert_eq!(RenderRequest::from_bytes(&bytes), req);
+    assert_eq!(RenderRequest::from_bytes(&req.as_bytes()), plain);
+}
+
+#[test]
+fn gray_level_is_clamped() {
+    let req = RenderRequest::new(Point::zero(), 1, 0).with_level(0xff);
+    assert_eq!(req.level, MAX_GRAY_LEVEL);
+    assert_eq!(req.style(), Style::new(MAX_GRAY_LEVEL, 0));
+}
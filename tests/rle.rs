@@ -0,0 +1,71 @@
+use klaptik::*;
+use klaptik_codegen::{rle_encode, Sprite};
+
+fn samples() -> Vec<Vec<u8>> {
+    vec![
+        vec![],
+        vec![42],
+        vec![7, 7],
+        vec![0; 300],
+        (0..300).map(|idx| idx as u8).collect(),
+        (0..300).map(|idx| (idx / 5) as u8 & 0x81).collect(),
+        (0..257)
+            .map(|idx| if idx % 3 == 0 { 0xaa } else { 0 })
+            .collect(),
+    ]
+}
+
+#[test]
+fn rle_round_trip() {
+    for data in samples() {
+        let encoded = rle_encode(&data);
+        assert_eq!(RleDecoder::new(&encoded).collect::<Vec<_>>(), data);
+    }
+}
+
+#[test]
+fn rle_fill_pads_with_zeros() {
+    let encoded = rle_encode(&[1, 2, 3, 3, 3]);
+    let mut decoder = RleDecoder::new(&encoded);
+
+    let mut buf = [0xff; 4];
+    decoder.fill(&mut buf);
+    assert_eq!(buf, [1, 2, 3, 3]);
+
+    let mut buf = [0xff; 4];
+    decoder.fill(&mut buf);
+    assert_eq!(buf, [3, 0, 0, 0]);
+}
+
+#[test]
+fn rle_sprite_matches_raw_sprite() {
+    let sprite = Sprite {
+        width: 8,
+        height: 16,
+        glyphs: 3,
+        bitmap: (0..48)
+            .map(|idx| if idx % 16 < 10 { 0 } else { idx as u8 })
+            .collect(),
+    };
+    let (offsets, data) = sprite.compress().unwrap();
+    let size = Size::new(8, 16);
+    let raw = FlashSprite::new(1, 3, size, sprite.bitmap.clone().leak());
+    let rle = FlashSprite::rle(1, 3, size, offsets.leak(), data.leak());
+    assert!(rle.is_compressed());
+
+    for glyph in 0..3 {
+        let decoded: Vec<_> = rle.glyph_rle(glyph).unwrap().collect();
+        assert_eq!(decoded, raw.glyph_bitmap(glyph).unwrap());
+    }
+
+    let mut expected =
+        SpriteDisplay::new(FrameBuffer::<32, 3>::new(), [(raw, Glyphs::Sequential(3))]);
+    let mut actual =
+        SpriteDisplay::new(FrameBuffer::<32, 3>::new(), [(rle, Glyphs::Sequential(3))]);
+    for glyph in 0..3u8 {
+        let req = RenderRequest::new(Point::new(Coord::from(glyph) * 9, 3), 1, glyph);
+        expected.render(req);
+        actual.render(req);
+    }
+    assert_eq!(actual.canvas().pages(), expected.canvas().pages());
+}
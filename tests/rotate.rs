@@ -0,0 +1,88 @@
+use klaptik::*;
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+fn lit<const W: usize, const PAGES: usize>(fb: &FrameBuffer<W, PAGES>) -> Vec<(usize, usize)> {
+    let mut pixels = Vec::new();
+    for y in 0..FrameBuffer::<W, PAGES>::HEIGHT {
+        for x in 0..W {
+            if fb.pixel(x, y) {
+                pixels.push((x, y));
+            }
+        }
+    }
+    pixels
+}
+
+// 8x8 sprite with a single pixel at (1, 2).
+const DOT: [u8; 8] = [0, 1 << 2, 0, 0, 0, 0, 0, 0];
+
+fn rotated(rotation: Rotation, mirror: bool) -> Vec<(usize, usize)> {
+    let mut fb = FrameBuffer::<16, 1>::new();
+    let mut canvas = RotateCanvas::new(&mut fb, Size::new(16, 8), rotation);
+    canvas.set_mirror(mirror);
+    canvas.draw(rect(0, 0, 8, 8), &DOT);
+    lit(&fb)
+}
+
+#[test]
+fn rotate_canvas_maps_pixels() {
+    assert_eq!(rotated(Rotation::Deg0, false), [(1, 2)]);
+    assert_eq!(rotated(Rotation::Deg90, false), [(13, 1)]);
+    assert_eq!(rotated(Rotation::Deg180, false), [(14, 5)]);
+    assert_eq!(rotated(Rotation::Deg270, false), [(2, 6)]);
+    assert_eq!(rotated(Rotation::Deg0, true), [(14, 2)]);
+    assert_eq!(rotated(Rotation::Deg90, true), [(2, 1)]);
+}
+
+#[test]
+fn rotate_canvas_swaps_size() {
+    let fb = FrameBuffer::<16, 1>::new();
+    let mut canvas = RotateCanvas::new(fb, Size::new(16, 8), Rotation::Deg0);
+    assert_eq!(canvas.size(), Size::new(16, 8));
+    canvas.set_rotation(Rotation::Deg270);
+    assert_eq!(canvas.size(), Size::new(8, 16));
+    assert_eq!(canvas.panel(), Size::new(16, 8));
+}
+
+#[test]
+fn rotate_canvas_clips_to_logical_size() {
+    let mut fb = FrameBuffer::<16, 1>::new();
+    let mut canvas = RotateCanvas::new(&mut fb, Size::new(16, 8), Rotation::Deg90);
+    canvas.draw(rect(4, 12, 8, 8), &[0xff; 8]);
+    assert_eq!(lit(&fb).len(), 16);
+    assert!(lit(&fb).iter().all(|(x, y)| *x < 4 && (4..8).contains(y)));
+}
+
+#[test]
+fn rotate_canvas_round_trip() {
+    let bitmap: Vec<u8> = (0..24u8).map(|idx| idx.wrapping_mul(53) ^ 0x3c).collect();
+    let mut expected = FrameBuffer::<8, 3>::new();
+    expected.draw(rect(0, 0, 8, 24), &bitmap);
+
+    for rotation in [Rotation::Deg90, Rotation::Deg180, Rotation::Deg270] {
+        let panel = if rotation.is_transposed() {
+            Size::new(24, 8)
+        } else {
+            Size::new(8, 24)
+        };
+        let mut rotated = FrameBuffer::<24, 3>::new();
+        RotateCanvas::new(&mut rotated, panel, rotation).draw(rect(0, 0, 8, 24), &bitmap);
+
+        let mut pixels = 0;
+        for y in 0..24 {
+            for x in 0..8 {
+                let (px, py) = match rotation {
+                    Rotation::Deg90 => (23 - y, x),
+                    Rotation::Deg180 => (7 - x, 23 - y),
+                    _ => (y, 7 - x),
+                };
+                assert_eq!(rotated.pixel(px, py), expected.pixel(x, y));
+                pixels += expected.pixel(x, y) as usize;
+            }
+        }
+        assert_eq!(lit(&rotated).len(), pixels);
+    }
+}
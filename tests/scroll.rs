@@ -0,0 +1,162 @@
+use klaptik::*;
+use std::convert::Infallible;
+
+const BLANK: Glyph = b' ';
+
+struct Panel {
+    requests: Vec<RenderRequest>,
+    start_lines: Vec<Coord>,
+    lines: Option<Coord>,
+}
+
+impl Panel {
+    fn new(lines: Option<Coord>) -> Self {
+        Self {
+            requests: Vec::new(),
+            start_lines: Vec::new(),
+            lines,
+        }
+    }
+
+    fn glyph_at(&self, x: Coord, y: Coord) -> Option<Glyph> {
+        self.requests
+            .iter()
+            .rev()
+            .find(|req| req.origin == Point::new(x, y))
+            .map(|req| req.glyph)
+    }
+}
+
+impl TryDisplay for Panel {
+    type Error = Infallible;
+
+    fn try_render(&mut self, req: RenderRequest) -> Result<(), Self::Error> {
+        self.requests.push(req);
+        Ok(())
+    }
+
+    fn try_scroll(&mut self, start_line: Coord) -> Result<Option<Coord>, Self::Error> {
+        self.start_lines.push(start_line);
+        Ok(self.lines)
+    }
+}
+
+fn view<const ROWS: usize>(lines: usize) -> ScrollView<4, ROWS> {
+    let mut view = ScrollView::new(1, 0, Size::new(8, 8), BLANK);
+    for line in 0..lines {
+        view.push_line(&[b'a' + line as u8]);
+    }
+    view
+}
+
+#[test]
+fn hardware_scroll_draws_rows_in_place() {
+    let mut panel = Panel::new(Some(64));
+    let mut view = view::<8>(8);
+    view.render(&mut panel);
+
+    assert_eq!(panel.start_lines, [0, 8]);
+    assert_eq!(panel.requests.len(), 32);
+    assert_eq!(panel.glyph_at(0, 0), Some(BLANK));
+    assert_eq!(panel.glyph_at(0, 8), Some(b'b'));
+    assert_eq!(panel.glyph_at(0, 56), Some(b'h'));
+    assert!(!view.is_scrolling());
+
+    panel.requests.clear();
+    view.set_step(4);
+    view.push_line(b"i");
+    view.render(&mut panel);
+    assert_eq!(panel.glyph_at(0, 0), Some(b'i'));
+    assert_eq!(panel.glyph_at(0, 8), Some(BLANK));
+    assert_eq!(panel.requests.len(), 8);
+    assert!(view.is_scrolling());
+
+    panel.requests.clear();
+    view.render(&mut panel);
+    assert!(panel.requests.is_empty());
+    assert_eq!(panel.start_lines, [0, 8, 12, 16]);
+    assert!(!view.is_scrolling());
+}
+
+#[test]
+fn mismatched_scroll_area_falls_back_to_redraw() {
+    let mut panel = Panel::new(Some(64));
+    let mut view = view::<4>(4);
+    view.render(&mut panel);
+
+    assert_eq!(panel.start_lines, [0, 0]);
+    assert_eq!(panel.glyph_at(0, 0), Some(b'b'));
+    assert_eq!(panel.glyph_at(0, 16), Some(b'd'));
+    assert_eq!(panel.glyph_at(0, 24), Some(BLANK));
+
+    panel.requests.clear();
+    view.push_line(b"e");
+    view.render(&mut panel);
+    assert_eq!(panel.start_lines, [0, 0]);
+    assert_eq!(panel.requests.len(), 16);
+    assert_eq!(panel.glyph_at(0, 0), Some(b'c'));
+    assert_eq!(panel.glyph_at(0, 16), Some(b'e'));
+
+    view.invalidate();
+    view.render(&mut panel);
+    assert_eq!(panel.start_lines, [0, 0, 16, 0]);
+}
+
+#[test]
+fn software_scroll_redraws_visible_rows() {
+    let mut display = RecordingDisplay::<64>::new();
+    let mut view = view::<4>(3);
+    view.render(&mut display);
+    display.assert_glyph_at(Point::new(0, 0), 1, b'a');
+    display.assert_glyph_at(Point::new(0, 16), 1, b'c');
+
+    display.clear();
+    view.push_line(b"d");
+    view.push_line(b"e");
+    view.render(&mut display);
+    display.assert_redraws(16);
+    display.assert_glyph_at(Point::new(0, 0), 1, b'c');
+    display.assert_glyph_at(Point::new(0, 8), 1, b'd');
+    display.assert_glyph_at(Point::new(0, 16), 1, b'e');
+    display.assert_glyph_at(Point::new(0, 24), 1, BLANK);
+}
+
+#[test]
+fn scroll_is_forwarded_through_wrappers() {
+    let area = Rectangle::new(Point::new(8, 8), Size::new(32, 32));
+    let mut viewport = Viewport::new(Panel::new(Some(64)), area);
+    assert_eq!(viewport.try_scroll(8), Ok(Some(64)));
+    assert_eq!(viewport.display().start_lines, [8]);
+
+    let mut queue = RenderQueue::<_, 4>::new(Panel::new(Some(64)));
+    assert_eq!(queue.try_scroll(8), Ok(Some(64)));
+    assert_eq!(queue.display().start_lines, [8]);
+}
+
+#[test]
+fn mirrors_report_common_scroll_area() {
+    let mut pair = (Panel::new(Some(64)), Panel::new(Some(64)));
+    assert_eq!(pair.try_scroll(8), Ok(Some(64)));
+
+    let mut pair = (Panel::new(Some(64)), Panel::new(None));
+    assert_eq!(pair.try_scroll(8), Ok(None));
+    assert_eq!(pair.0.start_lines, [8]);
+    assert_eq!(pair.1.start_lines, [8]);
+
+    let mut triple = (
+        Panel::new(Some(64)),
+        Panel::new(Some(64)),
+        Panel::new(Some(32)),
+    );
+    assert_eq!(triple.try_scroll(8), Ok(None));
+    assert_eq!(triple.2.start_lines, [8]);
+
+    let mut panels = [Panel::new(Some(64)), Panel::new(Some(64))];
+    assert_eq!(panels.try_scroll(8), Ok(Some(64)));
+    assert_eq!(panels[..1].try_scroll(16), Ok(Some(64)));
+    assert_eq!(panels[0].start_lines, [8, 16]);
+    assert_eq!(panels[1].start_lines, [8]);
+
+    let mut panels = [Panel::new(Some(64)), Panel::new(None)];
+    assert_eq!(panels.try_scroll(8), Ok(None));
+}
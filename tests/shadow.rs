@@ -0,0 +1,82 @@
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Spans(Vec<(Rectangle, Vec<u8>)>);
+
+impl TryCanvas for Spans {
+    type Error = Infallible;
+
+    fn try_draw(&mut self, bounds: Rectangle, bitmap: &[u8]) -> Result<(), Self::Error> {
+        self.0.push((bounds, bitmap.to_vec()));
+        Ok(())
+    }
+}
+
+fn rect(x: Coord, y: Coord, width: Coord, height: Coord) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+#[test]
+fn shadow_canvas_passes_aligned_draws_through() {
+    let mut canvas = ShadowCanvas::<_, 16, 3>::new(Spans::default());
+    canvas.draw(rect(0, 8, 4, 8), &[1, 2, 3, 4]);
+
+    assert_eq!(canvas.canvas().0, [(rect(0, 8, 4, 8), vec![1, 2, 3, 4])]);
+    assert_eq!(&canvas.shadow().page(1)[..5], &[1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn shadow_canvas_merges_unaligned_draws() {
+    let mut canvas = ShadowCanvas::<_, 16, 3>::new(Spans::default());
+    canvas.draw(rect(0, 0, 4, 8), &[0x01; 4]);
+    canvas.canvas().0.clear();
+
+    canvas.draw(rect(2, 5, 2, 12), &[0xff, 0xff, 0x0f, 0x0f]);
+    assert_eq!(
+        canvas.canvas().0,
+        [
+            (rect(2, 0, 2, 8), vec![0xe1, 0xe1]),
+            (rect(2, 8, 2, 8), vec![0xff, 0xff]),
+            (rect(2, 16, 2, 8), vec![0x01, 0x01]),
+        ]
+    );
+    assert_eq!(&canvas.shadow().page(0)[..4], &[0x01, 0x01, 0xe1, 0xe1]);
+}
+
+#[test]
+fn shadow_canvas_clips_to_screen() {
+    let mut canvas = ShadowCanvas::<_, 16, 3>::new(Spans::default());
+    canvas.draw(rect(14, 0, 4, 8), &[1, 2, 3, 4]);
+    canvas.draw(rect(16, 0, 4, 8), &[1, 2, 3, 4]);
+
+    assert_eq!(canvas.canvas().0, [(rect(14, 0, 2, 8), vec![1, 2])]);
+}
+
+#[cfg(feature = "st7567")]
+#[test]
+fn page_drivers_reject_unaligned_draws() {
+    use embedded_hal::blocking::spi;
+    use klaptik::drivers::spi::{Error, NoCS, NoDC};
+    use klaptik::drivers::st7567::ST7567;
+
+    struct Bus;
+
+    impl spi::Write<u8> for Bus {
+        type Error = Infallible;
+
+        fn write(&mut self, _: &[u8]) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    let mut lcd = ST7567::new(Bus, NoCS, NoDC, NoCS);
+    assert!(matches!(
+        lcd.try_draw(rect(0, 5, 2, 8), &[0; 2]),
+        Err(Error::InvalidArgument)
+    ));
+    assert!(lcd.try_draw(rect(0, 8, 2, 8), &[0; 2]).is_ok());
+
+    let mut shadowed = ShadowCanvas::<_, 128, 8>::new(lcd);
+    assert!(shadowed.try_draw(rect(0, 5, 2, 12), &[0; 4]).is_ok());
+}
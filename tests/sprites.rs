@@ -0,0 +1,78 @@
+use klaptik::*;
+
+const ARROWS: FlashSprite = sprite!(
+    3,
+    ["..#..", ".###.", "#.#.#", "..#..", "..#.."],
+    ["..#..", "..#..", "#.#.#", ".###.", "..#.."],
+);
+
+#[test]
+fn ascii_sprite_geometry() {
+    assert_eq!(ARROWS.id(), 3);
+    assert_eq!(ARROWS.glyphs(), 2);
+    assert_eq!(ARROWS.size(), Size::new(5, 8));
+    assert_eq!(ARROWS.depth(), BitDepth::Mono);
+    assert!(!ARROWS.is_compressed());
+}
+
+#[test]
+fn ascii_sprite_bitmap() {
+    assert_eq!(
+        ARROWS.glyph_bitmap(0),
+        Some(&[0x04, 0x02, 0x1f, 0x02, 0x04][..])
+    );
+    assert_eq!(
+        ARROWS.glyph_bitmap(1),
+        Some(&[0x04, 0x08, 0x1f, 0x08, 0x04][..])
+    );
+    assert_eq!(ARROWS.glyph_bitmap(2), None);
+}
+
+#[test]
+fn ascii_sprite_spans_pages() {
+    let tall = sprite!(1, ["#", ".", ".", ".", ".", ".", ".", ".", "#"]);
+    assert_eq!(tall.size(), Size::new(1, 16));
+    assert_eq!(tall.glyph_bitmap(0), Some(&[0x01, 0x01][..]));
+}
+
+#[test]
+fn sprite_geometry_is_validated() {
+    const BITMAP: [u8; 16] = [0; 16];
+
+    assert!(FlashSprite::try_new(1, 2, Size::new(8, 8), &BITMAP).is_ok());
+    assert_eq!(
+        FlashSprite::try_new(1, 0, Size::new(8, 8), &BITMAP).err(),
+        Some(SpriteError::NoGlyphs)
+    );
+    assert_eq!(
+        FlashSprite::try_new(1, 2, Size::new(0, 8), &BITMAP).err(),
+        Some(SpriteError::EmptySize)
+    );
+    assert_eq!(
+        FlashSprite::try_new(1, 1, Size::new(2, 12), &BITMAP).err(),
+        Some(SpriteError::UnalignedHeight)
+    );
+    assert_eq!(
+        FlashSprite::try_new(1, 3, Size::new(8, 8), &BITMAP).err(),
+        Some(SpriteError::BitmapLength)
+    );
+    assert_eq!(
+        FlashSprite::try_rle(1, 2, Size::new(8, 8), &[0], &BITMAP).err(),
+        Some(SpriteError::GlyphOffsets)
+    );
+    assert_eq!(
+        FlashSprite::try_rle(1, 2, Size::new(8, 8), &[4, 2], &BITMAP).err(),
+        Some(SpriteError::GlyphOffsets)
+    );
+    assert_eq!(
+        FlashSprite::try_rle(1, 2, Size::new(8, 8), &[0, 17], &BITMAP).err(),
+        Some(SpriteError::GlyphOffsets)
+    );
+}
+
+#[test]
+#[should_panic(expected = "sprite height must be a multiple of 8")]
+fn invalid_sprite_panics() {
+    static BITMAP: [u8; 4] = [0; 4];
+    FlashSprite::new(1, 1, Size::new(2, 12), &BITMAP);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "st7567")]
+
+use embedded_hal::blocking::spi;
+use klaptik::drivers::spi::{Error, NoCS, NoDC};
+use klaptik::drivers::st7567::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Bus(Vec<u8>);
+
+impl spi::Write<u8> for Bus {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.0.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+fn lcd() -> ST7567<Bus, NoCS, NoCS, NoDC> {
+    ST7567::new(Bus::default(), NoCS, NoDC, NoCS)
+}
+
+fn written(lcd: ST7567<Bus, NoCS, NoCS, NoDC>) -> Vec<u8> {
+    let (bus, _, _, _) = lcd.release();
+    bus.0
+}
+
+#[test]
+fn config_validation() {
+    assert!(Config::new().is_valid());
+    assert_eq!(Config::default(), Config::new());
+
+    let max = Config {
+        regulator_ratio: MAX_REGULATOR_RATIO,
+        contrast: MAX_CONTRAST,
+        start_line: MAX_START_LINE,
+        ..Config::new()
+    };
+    assert!(max.is_valid());
+    assert!(!Config {
+        regulator_ratio: 8,
+        ..max
+    }
+    .is_valid());
+    assert!(!Config {
+        contrast: 0x40,
+        ..max
+    }
+    .is_valid());
+    assert!(!Config {
+        start_line: 0x40,
+        ..max
+    }
+    .is_valid());
+}
+
+#[test]
+fn configure_rejects_invalid_config() {
+    let mut lcd = lcd();
+    let config = Config {
+        contrast: 0x40,
+        bias: Bias::OneSeventh,
+        ..Config::new()
+    };
+    assert!(matches!(lcd.configure(config), Err(Error::InvalidArgument)));
+    assert_eq!(lcd.config(), Config::new());
+    assert!(written(lcd).is_empty());
+}
+
+#[test]
+fn configure_applies_config() {
+    let mut lcd = lcd();
+    let config = Config {
+        bias: Bias::OneSeventh,
+        regulator_ratio: 0x05,
+        contrast: 0x30,
+        flip_segments: true,
+        flip_com: true,
+        inverse: true,
+        start_line: 0x10,
+    };
+    assert!(lcd.configure(config).is_ok());
+    assert_eq!(lcd.config(), config);
+    assert_eq!(
+        written(lcd),
+        [0xa3, 0x25, 0x81, 0x30, 0xa1, 0xc8, 0xa7, 0x50]
+    );
+}
+
+#[test]
+fn setters_reject_out_of_range_values() {
+    let mut lcd = lcd();
+    assert!(matches!(
+        lcd.set_regulator_ratio(MAX_REGULATOR_RATIO + 1),
+        Err(Error::InvalidArgument)
+    ));
+    assert!(matches!(
+        lcd.set_contrast(MAX_CONTRAST + 1),
+        Err(Error::InvalidArgument)
+    ));
+    assert!(matches!(
+        lcd.set_start_line(MAX_START_LINE + 1),
+        Err(Error::InvalidArgument)
+    ));
+    assert_eq!(lcd.config(), Config::new());
+
+    assert!(lcd.set_regulator_ratio(MAX_REGULATOR_RATIO).is_ok());
+    assert!(lcd.set_contrast(MAX_CONTRAST).is_ok());
+    assert!(lcd.set_start_line(MAX_START_LINE).is_ok());
+    assert_eq!(lcd.config().regulator_ratio, MAX_REGULATOR_RATIO);
+    assert_eq!(lcd.config().contrast, MAX_CONTRAST);
+    assert_eq!(lcd.config().start_line, MAX_START_LINE);
+    assert_eq!(written(lcd), [0x27, 0x81, 0x3f, 0x7f]);
+}
+
+#[test]
+fn power_commands() {
+    let mut lcd = lcd();
+    lcd.power_on().unwrap();
+    lcd.sleep().unwrap();
+    lcd.wake().unwrap();
+    lcd.power_off().unwrap();
+    assert_eq!(written(lcd), [0x2f, 0xae, 0xa5, 0xa4, 0xaf, 0x28]);
+}
@@ -0,0 +1,53 @@
+use klaptik::*;
+
+const DIGITS: FlashSprite = sprite!(1, ["#.", "#."], [".#", ".#"], ["##", ".."],);
+const CURSOR: FlashSprite = sprite!(9, ["###"]);
+
+static INDEX: GlyphIndex = GlyphIndex::new(b"xyz");
+
+fn render(req: RenderRequest) -> FrameBuffer<8, 1> {
+    let sprites = SpriteMap::new([(DIGITS, Glyphs::Indexed(&INDEX)), (CURSOR, Glyphs::Single)]);
+    let mut display = SpriteDisplay::new(FrameBuffer::new(), sprites);
+    display.render(req);
+    std::mem::take(display.canvas())
+}
+
+#[test]
+fn sprite_map_finds_sprites_by_id() {
+    let sprites = SpriteMap::new([(DIGITS, Glyphs::Sequential(3)), (CURSOR, Glyphs::Single)]);
+    assert_eq!(sprites.get(9).map(|(sprite, _)| sprite.id()), Some(9));
+    assert_eq!(sprites.sprite_size(1), Some(Size::new(2, 8)));
+    assert_eq!(sprites.sprite_size(9), Some(Size::new(3, 8)));
+    assert_eq!(sprites.sprite_size(2), None);
+}
+
+#[test]
+fn sprite_map_draws_indexed_glyphs() {
+    let fb = render(RenderRequest::new(Point::new(2, 0), 1, b'y'));
+    assert_eq!(fb.page(0), &[0, 0, 0, 0x03, 0, 0, 0, 0]);
+
+    let fb = render(RenderRequest::new(Point::new(2, 0), 9, 0));
+    assert_eq!(fb.page(0), &[0, 0, 1, 1, 1, 0, 0, 0]);
+
+    let fb = render(RenderRequest::new(Point::zero(), 1, b'a'));
+    assert_eq!(fb.page(0), &[0; 8]);
+
+    let fb = render(RenderRequest::new(Point::zero(), 5, b'x'));
+    assert_eq!(fb.page(0), &[0; 8]);
+}
+
+#[test]
+fn glyph_index_lookup() {
+    assert_eq!(INDEX.len(), 3);
+    assert_eq!(INDEX.index(b'x'), Some(0));
+    assert_eq!(INDEX.index(b'z'), Some(2));
+    assert_eq!(INDEX.index(b'w'), None);
+    assert_eq!(Glyphs::Alphabet(b"ab").index(b'b'), Some(1));
+    assert_eq!(Glyphs::Sequential(4).index(4), None);
+}
+
+#[test]
+#[should_panic(expected = "duplicate sprite id")]
+fn sprite_map_rejects_duplicate_ids() {
+    SpriteMap::new([(CURSOR, Glyphs::Single), (CURSOR, Glyphs::Single)]);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "tft")]
+
+use embedded_hal::blocking::spi;
+use klaptik::drivers::spi::{NoCS, NoDC};
+use klaptik::drivers::tft::{Palette, Rgb565, Tft, TftModel};
+use klaptik::*;
+use std::convert::Infallible;
+
+#[derive(Default)]
+struct Bus(Vec<u8>);
+
+impl spi::Write<u8> for Bus {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.0.extend_from_slice(words);
+        Ok(())
+    }
+}
+
+fn tft() -> Tft<Bus, NoCS, NoCS, NoDC> {
+    Tft::new(TftModel::ST7789, Bus::default(), NoCS, NoDC, NoCS)
+}
+
+fn pixels(tft: Tft<Bus, NoCS, NoCS, NoDC>, count: usize) -> Vec<u16> {
+    let (bus, _, _, _) = tft.release();
+    bus.0[bus.0.len() - count * 2..]
+        .chunks(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+#[test]
+fn rgb565_blend() {
+    assert_eq!(Rgb565::WHITE, Rgb565(0xffff));
+    assert_eq!(Rgb565::RED, Rgb565(0xf800));
+    assert_eq!(Rgb565::BLACK.blend(Rgb565::WHITE, 0), Rgb565::BLACK);
+    assert_eq!(
+        Rgb565::BLACK.blend(Rgb565::WHITE, MAX_GRAY_LEVEL),
+        Rgb565::WHITE
+    );
+    assert_eq!(Rgb565::BLUE.blend(Rgb565::RED, 7), Rgb565(0x7010));
+    assert_eq!(
+        Palette::new(Rgb565::RED, Rgb565::BLUE).color(7),
+        Rgb565(0x7010)
+    );
+}
+
+#[test]
+fn mono_sprite_uses_selected_palette() {
+    let mut tft = tft();
+    tft.set_palette(3, Palette::new(Rgb565::RED, Rgb565::BLUE));
+    assert_eq!(tft.palette(0), Palette::new(Rgb565::WHITE, Rgb565::BLACK));
+    assert_eq!(tft.palette(3 + 16), Palette::new(Rgb565::RED, Rgb565::BLUE));
+
+    let bounds = Rectangle::new(Point::zero(), Size::new(2, 8));
+    tft.try_draw_gray(
+        bounds,
+        &[0x01, 0x02],
+        BitDepth::Mono,
+        Style::new(MAX_GRAY_LEVEL, 3),
+    )
+    .unwrap();
+    let pixels = pixels(tft, 16);
+    assert_eq!(pixels[..4], [0xf800, 0x001f, 0x001f, 0xf800]);
+    assert!(pixels[4..].iter().all(|pixel| *pixel == 0x001f));
+}
+
+#[test]
+fn mono_sprite_dims_by_level() {
+    let mut tft = tft();
+    tft.set_palettes(Palette::new(Rgb565::RED, Rgb565::BLUE));
+    let bounds = Rectangle::new(Point::zero(), Size::new(1, 8));
+    tft.try_draw_gray(bounds, &[0x01], BitDepth::Mono, Style::new(7, 9))
+        .unwrap();
+    let pixels = pixels(tft, 8);
+    assert_eq!(pixels[..2], [0x7010, 0x001f]);
+}
+
+#[test]
+fn gray_sprite_blends_into_palette() {
+    let mut tft = tft();
+    tft.set_palette(1, Palette::new(Rgb565::WHITE, Rgb565::BLACK));
+    let bounds = Rectangle::new(Point::zero(), Size::new(4, 8));
+    let ramp = [0, 0, 1, 1, 0, 1, 0, 1];
+    tft.try_draw_gray(
+        bounds,
+        &ramp,
+        BitDepth::Gray2,
+        Style::new(MAX_GRAY_LEVEL, 1),
+    )
+    .unwrap();
+    let pixels = pixels(tft, 32);
+    let expected: Vec<_> = [0, 5, 10, 15]
+        .iter()
+        .map(|level| Rgb565::BLACK.blend(Rgb565::WHITE, *level).0)
+        .collect();
+    assert_eq!(pixels[..4], expected[..]);
+}
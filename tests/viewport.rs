@@ -0,0 +1,66 @@
+use klaptik::*;
+
+fn req(x: Coord, y: Coord) -> RenderRequest {
+    RenderRequest::new(Point::new(x, y), 1, 0)
+}
+
+fn viewport(x: Coord, y: Coord) -> Viewport<RecordingDisplay<8>> {
+    let area = Rectangle::new(Point::new(x, y), Size::new(32, 16));
+    Viewport::new(RecordingDisplay::new(), area)
+}
+
+#[test]
+fn viewport_translates_requests() {
+    let mut viewport = viewport(16, 8);
+    viewport.render(req(0, 0));
+    viewport.render(req(31, 15));
+    viewport.render(req(32, 0));
+    viewport.render(req(0, 16));
+
+    let display = viewport.display();
+    display.assert_redraws(2);
+    display.assert_glyph_at(Point::new(16, 8), 1, 0);
+    display.assert_glyph_at(Point::new(47, 23), 1, 0);
+}
+
+#[test]
+fn viewport_applies_shift() {
+    let mut viewport = viewport(16, 8);
+    viewport.set_shift(2, -1);
+    assert_eq!(viewport.shift(), (2, -1));
+    assert_eq!(
+        viewport.translate(Point::new(4, 4)),
+        Some(Point::new(22, 11))
+    );
+
+    viewport.set_origin(Point::new(40, 0));
+    assert_eq!(viewport.area().origin, Point::new(40, 0));
+    assert_eq!(
+        viewport.translate(Point::new(4, 4)),
+        Some(Point::new(46, 3))
+    );
+}
+
+#[test]
+fn negative_shift_clamps_at_panel_edge() {
+    let mut viewport = viewport(0, 0);
+    viewport.set_shift(-1, -2);
+    viewport.render(req(0, 0));
+    viewport.render(req(8, 8));
+
+    let display = viewport.display();
+    display.assert_redraws(2);
+    display.assert_glyph_at(Point::new(0, 0), 1, 0);
+    display.assert_glyph_at(Point::new(7, 6), 1, 0);
+}
+
+#[test]
+fn positive_shift_clamps_at_coordinate_limit() {
+    let area = Rectangle::new(Point::new(Coord::MAX - 4, 0), Size::new(8, 8));
+    let mut viewport = Viewport::new(RecordingDisplay::<2>::new(), area);
+    viewport.set_shift(8, 0);
+    assert_eq!(
+        viewport.translate(Point::new(4, 0)),
+        Some(Point::new(Coord::MAX, 0))
+    );
+}
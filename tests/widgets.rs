@@ -0,0 +1,160 @@
+use klaptik::*;
+
+const FONT: SpriteId = 1;
+const ICONS: SpriteId = 2;
+
+#[test]
+fn icon_renders_on_change() {
+    let mut display = RecordingDisplay::<8>::new();
+    let mut icon = GlyphIcon::new(ICONS, 3, Point::new(8, 16));
+
+    icon.render(&mut display);
+    display.assert_glyph_at(Point::new(8, 16), ICONS, 3);
+
+    icon.update(3);
+    icon.render(&mut display);
+    display.assert_redraws(1);
+
+    icon.update(4);
+    icon.render(&mut display);
+    display.assert_glyph_at(Point::new(8, 16), ICONS, 4);
+    display.assert_redraws(2);
+    display.assert_no_redundant();
+}
+
+#[test]
+fn icon_carries_style() {
+    let mut display = RecordingDisplay::<4>::new();
+    let mut icon = GlyphIcon::new(ICONS, 0, Point::zero());
+    icon.render(&mut display);
+
+    icon.set_level(7);
+    icon.set_palette(3);
+    icon.set_attributes(Attributes::INVERT);
+    icon.render(&mut display);
+    display.assert_redraws(2);
+
+    let req = display.last_at(Point::zero()).unwrap();
+    assert_eq!(req.style(), Style::new(7, 3));
+    assert_eq!(req.attributes, Attributes::INVERT);
+}
+
+#[test]
+fn label_redraws_changed_glyphs_only() {
+    let mut display = RecordingDisplay::<16>::new();
+    let mut label = Label::<4>::new(FONT, "abcd", Point::new(0, 8), Size::new(6, 8));
+
+    label.render(&mut display);
+    display.assert_redraws(4);
+    for (idx, glyph) in b"abcd".iter().enumerate() {
+        display.assert_glyph_at(Point::new(idx as Coord * 6, 8), FONT, *glyph);
+    }
+
+    display.clear();
+    label.update(b"abzd");
+    label.render(&mut display);
+    display.assert_redraws(1);
+    display.assert_glyph_at(Point::new(12, 8), FONT, b'z');
+}
+
+#[test]
+fn grid_invalidates_matching_sprite() {
+    let mut display = RecordingDisplay::<16>::new();
+    let mut label = VerticalLabel::<2>::new(FONT, "ab", Point::zero(), Size::new(6, 8));
+    label.render(&mut display);
+    display.assert_glyph_at(Point::new(0, 8), FONT, b'b');
+
+    display.clear();
+    label.invalidate_sprite(ICONS);
+    label.render(&mut display);
+    display.assert_redraws(0);
+
+    label.invalidate_sprite(FONT);
+    label.render(&mut display);
+    display.assert_redraws(2);
+}
+
+#[test]
+fn tile_fills_area() {
+    let mut display = RecordingDisplay::<8>::new();
+    let mut tile = Tile::new(ICONS, 5u8, Point::new(4, 8), Size::new(8, 8), 3, 2);
+
+    tile.render(&mut display);
+    display.assert_redraws(6);
+    for x in 0..3 {
+        for y in 0..2 {
+            display.assert_glyph_at(Point::new(4 + x * 8, 8 + y * 8), ICONS, 5);
+        }
+    }
+
+    display.clear();
+    tile.update(5);
+    tile.render(&mut display);
+    display.assert_redraws(0);
+
+    tile.update(6);
+    tile.render(&mut display);
+    display.assert_redraws(6);
+    display.assert_glyph_at(Point::new(20, 16), ICONS, 6);
+}
+
+widget_group!(
+    StatusBar<u8>,
+    {
+        title: Label<2>, FONT, "ok", Point::zero(), Size::new(6, 8);
+        battery: GlyphIcon, ICONS, 0, Point::new(24, 0);
+    },
+    |bar: &mut StatusBar, level: u8| {
+        bar.battery.update(level);
+    }
+);
+
+#[test]
+fn widget_group_renders_children() {
+    let mut display = RecordingDisplay::<8>::new();
+    let mut bar = StatusBar::new();
+
+    bar.render(&mut display);
+    display.assert_redraws(3);
+    display.assert_glyph_at(Point::new(6, 0), FONT, b'k');
+    display.assert_glyph_at(Point::new(24, 0), ICONS, 0);
+
+    display.clear();
+    bar.update(2);
+    bar.render(&mut display);
+    display.assert_redraws(1);
+    display.assert_glyph_at(Point::new(24, 0), ICONS, 2);
+
+    display.clear();
+    bar.invalidate_sprite(FONT);
+    bar.render(&mut display);
+    display.assert_redraws(2);
+}
+
+widget_mux!(
+    Screen<u8>,
+    ScreenNode::Main,
+    {
+        main: GlyphIcon, ICONS, 1, Point::zero();
+        menu: GlyphIcon, ICONS, 2, Point::zero();
+    }
+);
+
+#[test]
+fn widget_mux_renders_active_node() {
+    let mut display = RecordingDisplay::<8>::new();
+    let mut screen = Screen::new();
+
+    screen.render(&mut display);
+    display.assert_redraws(1);
+    display.assert_glyph_at(Point::zero(), ICONS, 1);
+
+    screen.set_active(ScreenNode::Menu);
+    screen.render(&mut display);
+    display.assert_redraws(2);
+    display.assert_glyph_at(Point::zero(), ICONS, 2);
+
+    screen.set_active(ScreenNode::Menu);
+    screen.render(&mut display);
+    display.assert_redraws(2);
+}